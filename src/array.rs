@@ -0,0 +1,60 @@
+//! `Iterate` support for arrays
+
+use super::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterate, Iterator};
+use std::array;
+
+/// An iterator which yields the items of an array by value
+#[derive(Debug, Clone)]
+pub struct ArrayIter<T, const N: usize> {
+    iter: array::IntoIter<T, N>,
+}
+
+impl<T, const N: usize> Iterator for ArrayIter<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for ArrayIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ArrayIter<T, N> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<T, const N: usize> FusedIterator for ArrayIter<T, N> {}
+
+/// Iterates an array by value.
+///
+/// With `Iterate` in scope, `[1, 2].max([3, 4])` is ambiguous with `Ord::max`,
+/// and `a.eq(&b)`, `ne`, `lt`, `le`, `gt` and `ge` resolve to `Iterate`'s
+/// by-value methods instead of `PartialEq` and `PartialOrd`. Use
+/// `Ord::max(a, b)` and `PartialEq::eq(&a, &b)` for the element-wise
+/// comparisons. Arrays' inherent methods, such as `map`, still take
+/// precedence over `Iterate`'s.
+impl<T, const N: usize> Iterate for [T; N] {
+    type Item = T;
+
+    type Iterator = ArrayIter<T, N>;
+
+    fn iterate(self) -> Self::Iterator {
+        ArrayIter {
+            iter: IntoIterator::into_iter(self),
+        }
+    }
+}
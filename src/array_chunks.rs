@@ -0,0 +1,68 @@
+//! Helper types for the `array_chunks` operation
+
+use super::{FusedIterator, Iterate, Iterator};
+use std::array;
+
+/// An iterator which groups items into arrays of `N` items
+#[derive(Debug)]
+pub struct ArrayChunks<I, const N: usize> {
+    iter: I,
+}
+
+impl<I, const N: usize> ArrayChunks<I, N> {
+    fn new(iter: I) -> ArrayChunks<I, N> {
+        ArrayChunks { iter }
+    }
+}
+
+/// A type that can be converted into an array chunks iterator.
+#[derive(Debug, Clone)]
+pub struct IntoArrayChunks<I, const N: usize> {
+    iter: I,
+}
+
+impl<I, const N: usize> IntoArrayChunks<I, N> {
+    pub(crate) fn new(iter: I) -> Self {
+        assert!(N != 0, "chunk size must be non-zero");
+        Self { iter }
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for ArrayChunks<I, N> {
+    type Item = [I::Item; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<[I::Item; N]> {
+        let mut done = false;
+        let chunk: [Option<I::Item>; N] = array::from_fn(|_| {
+            if done {
+                return None;
+            }
+            let item = self.iter.next();
+            done = item.is_none();
+            item
+        });
+        if done {
+            return None;
+        }
+        Some(chunk.map(|item| item.expect("chunk was filled")))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (lower / N, upper.map(|upper| upper / N))
+    }
+}
+
+impl<I: FusedIterator, const N: usize> FusedIterator for ArrayChunks<I, N> {}
+
+impl<I: Iterate, const N: usize> Iterate for IntoArrayChunks<I, N> {
+    type Item = [I::Item; N];
+
+    type Iterator = ArrayChunks<I::Iterator, N>;
+
+    fn iterate(self) -> Self::Iterator {
+        ArrayChunks::new(self.iter.iterate())
+    }
+}
@@ -0,0 +1,41 @@
+//! Helpers for operations which need to buffer items
+
+use super::Iterator;
+use std::vec;
+
+/// Drain an iterator into a `Vec`.
+pub(crate) fn buffer<I: Iterator>(mut iter: I) -> Vec<I::Item> {
+    let mut items = Vec::with_capacity(iter.size_hint().0);
+    while let Some(item) = iter.next() {
+        items.push(item);
+    }
+    items
+}
+
+/// An iterator over buffered items.
+#[derive(Debug)]
+pub(crate) struct Buffer<T> {
+    iter: vec::IntoIter<T>,
+}
+
+impl<T> Buffer<T> {
+    pub(crate) fn new(items: Vec<T>) -> Buffer<T> {
+        Buffer {
+            iter: items.into_iter(),
+        }
+    }
+}
+
+impl<T> Iterator for Buffer<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
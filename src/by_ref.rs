@@ -0,0 +1,27 @@
+//! Helper types for the `by_ref` operation
+
+use super::{Iterate, Iterator};
+
+/// A type which borrows a live iterator so it can be used as an [`Iterate`].
+///
+/// This `struct` is created by the [`Iterator::by_ref`] method.
+#[derive(Debug)]
+pub struct ByRef<'a, I: ?Sized> {
+    iter: &'a mut I,
+}
+
+impl<'a, I: ?Sized> ByRef<'a, I> {
+    pub(crate) fn new(iter: &'a mut I) -> Self {
+        Self { iter }
+    }
+}
+
+impl<'a, I: Iterator + ?Sized> Iterate for ByRef<'a, I> {
+    type Item = I::Item;
+
+    type Iterator = &'a mut I;
+
+    fn iterate(self) -> Self::Iterator {
+        self.iter
+    }
+}
@@ -0,0 +1,94 @@
+//! Helper types for the `cartesian_product` operation
+
+use super::{FusedIterator, Iterate, Iterator};
+
+/// An iterator which yields every pair of items from two iterators
+#[derive(Debug)]
+pub struct CartesianProduct<A: Iterator, U: Iterate> {
+    a: A,
+    other: U,
+    current: Option<(A::Item, U::Iterator)>,
+}
+
+impl<A: Iterator, U: Iterate> CartesianProduct<A, U> {
+    fn new(a: A, other: U) -> CartesianProduct<A, U> {
+        CartesianProduct {
+            a,
+            other,
+            current: None,
+        }
+    }
+}
+
+/// A type that can be converted into a cartesian product iterator.
+#[derive(Debug, Clone)]
+pub struct IntoCartesianProduct<A, U> {
+    a: A,
+    other: U,
+}
+
+impl<A, U> IntoCartesianProduct<A, U> {
+    pub(crate) fn new(a: A, other: U) -> Self {
+        Self { a, other }
+    }
+}
+
+impl<A, U> Iterator for CartesianProduct<A, U>
+where
+    A: Iterator,
+    A::Item: Clone,
+    U: Iterate + Clone,
+{
+    type Item = (A::Item, U::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((a, inner)) = &mut self.current {
+                if let Some(b) = inner.next() {
+                    return Some((a.clone(), b));
+                }
+                self.current = None;
+            }
+            let a = self.a.next()?;
+            self.current = Some((a, self.other.clone().iterate()));
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = match &self.current {
+            Some((_, inner)) => inner.size_hint(),
+            None => (0, Some(0)),
+        };
+        // Without iterating `other` we can't tell how many items each
+        // remaining outer item contributes.
+        match self.a.size_hint() {
+            (_, Some(0)) => (lower, upper),
+            _ => (lower, None),
+        }
+    }
+}
+
+impl<A, U> FusedIterator for CartesianProduct<A, U>
+where
+    A: FusedIterator,
+    A::Item: Clone,
+    U: Iterate + Clone,
+{
+}
+
+impl<A, U> Iterate for IntoCartesianProduct<A, U>
+where
+    A: Iterate,
+    A::Item: Clone,
+    U: Iterate + Clone,
+{
+    type Item = (A::Item, U::Item);
+
+    type Iterator = CartesianProduct<A::Iterator, U>;
+
+    fn iterate(self) -> Self::Iterator {
+        CartesianProduct::new(self.a.iterate(), self.other)
+    }
+}
@@ -0,0 +1,67 @@
+//! Helper types for the `chunks` operation
+
+use super::{FusedIterator, Iterate, Iterator};
+
+/// An iterator which groups items into non-overlapping chunks
+#[derive(Debug)]
+pub struct Chunks<I> {
+    iter: I,
+    size: usize,
+}
+
+impl<I> Chunks<I> {
+    fn new(iter: I, size: usize) -> Chunks<I> {
+        Chunks { iter, size }
+    }
+}
+
+/// A type that can be converted into a chunks iterator.
+#[derive(Debug, Clone)]
+pub struct IntoChunks<I> {
+    iter: I,
+    size: usize,
+}
+
+impl<I> IntoChunks<I> {
+    pub(crate) fn new(iter: I, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        Self { iter, size }
+    }
+}
+
+impl<I: Iterator> Iterator for Chunks<I> {
+    type Item = Vec<I::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        let first = self.iter.next()?;
+        let mut chunk = Vec::with_capacity(self.size);
+        chunk.push(first);
+        while chunk.len() < self.size {
+            match self.iter.next() {
+                Some(item) => chunk.push(item),
+                None => break,
+            }
+        }
+        Some(chunk)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let chunks = |n: usize| n.div_ceil(self.size);
+        (chunks(lower), upper.map(chunks))
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for Chunks<I> {}
+
+impl<I: Iterate> Iterate for IntoChunks<I> {
+    type Item = Vec<I::Item>;
+
+    type Iterator = Chunks<I::Iterator>;
+
+    fn iterate(self) -> Self::Iterator {
+        Chunks::new(self.iter.iterate(), self.size)
+    }
+}
@@ -0,0 +1,89 @@
+//! Helper types for the `coalesce` operation
+
+use super::{FusedIterator, Iterate, Iterator};
+
+/// An iterator which merges adjacent items
+#[derive(Debug)]
+pub struct Coalesce<I: Iterator, F> {
+    iter: I,
+    f: F,
+    last: Option<I::Item>,
+}
+
+impl<I: Iterator, F> Coalesce<I, F> {
+    fn new(iter: I, f: F) -> Coalesce<I, F> {
+        Coalesce {
+            iter,
+            f,
+            last: None,
+        }
+    }
+}
+
+/// A type that can be converted into a coalescing iterator.
+#[derive(Debug, Clone)]
+pub struct IntoCoalesce<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> IntoCoalesce<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { iter, f }
+    }
+}
+
+impl<I: Iterator, F> Iterator for Coalesce<I, F>
+where
+    F: FnMut(I::Item, I::Item) -> Result<I::Item, (I::Item, I::Item)>,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        let mut acc = match self.last.take() {
+            Some(acc) => acc,
+            None => self.iter.next()?,
+        };
+        while let Some(item) = self.iter.next() {
+            match (self.f)(acc, item) {
+                Ok(merged) => acc = merged,
+                Err((prev, next)) => {
+                    self.last = Some(next);
+                    return Some(prev);
+                }
+            }
+        }
+        Some(acc)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let pending = self.last.is_some() as usize;
+        let lower = if lower.saturating_add(pending) > 0 {
+            1
+        } else {
+            0
+        };
+        (lower, upper.and_then(|upper| upper.checked_add(pending)))
+    }
+}
+
+impl<I: FusedIterator, F> FusedIterator for Coalesce<I, F> where
+    F: FnMut(I::Item, I::Item) -> Result<I::Item, (I::Item, I::Item)>
+{
+}
+
+impl<I: Iterate, F> Iterate for IntoCoalesce<I, F>
+where
+    F: FnMut(I::Item, I::Item) -> Result<I::Item, (I::Item, I::Item)>,
+{
+    type Item = I::Item;
+
+    type Iterator = Coalesce<I::Iterator, F>;
+
+    fn iterate(self) -> Self::Iterator {
+        Coalesce::new(self.iter.iterate(), self.f)
+    }
+}
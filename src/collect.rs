@@ -0,0 +1,127 @@
+//! `Collect` implementations for `std` types
+
+use super::shunt::try_process;
+use super::{Collect, Iterate, Iterator};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::hash::{BuildHasher, Hash};
+
+impl<A> Collect<A> for Vec<A> {
+    fn collect<T: Iterate<Item = A>>(iter: T) -> Self {
+        let mut iter = iter.iterate();
+        let mut vec = Vec::with_capacity(iter.size_hint().0);
+        while let Some(item) = iter.next() {
+            vec.push(item);
+        }
+        vec
+    }
+}
+
+impl<A> Collect<A> for () {
+    fn collect<T: Iterate<Item = A>>(iter: T) -> Self {
+        iter.for_each(drop);
+    }
+}
+
+impl<A> Collect<A> for Box<[A]> {
+    fn collect<T: Iterate<Item = A>>(iter: T) -> Self {
+        <Vec<A> as Collect<A>>::collect(iter).into_boxed_slice()
+    }
+}
+
+impl<A> Collect<A> for VecDeque<A> {
+    fn collect<T: Iterate<Item = A>>(iter: T) -> Self {
+        let mut iter = iter.iterate();
+        let mut deque = VecDeque::with_capacity(iter.size_hint().0);
+        while let Some(item) = iter.next() {
+            deque.push_back(item);
+        }
+        deque
+    }
+}
+
+impl Collect<char> for String {
+    fn collect<T: Iterate<Item = char>>(iter: T) -> Self {
+        let mut iter = iter.iterate();
+        let mut string = String::with_capacity(iter.size_hint().0);
+        while let Some(c) = iter.next() {
+            string.push(c);
+        }
+        string
+    }
+}
+
+impl<'a> Collect<&'a str> for String {
+    fn collect<T: Iterate<Item = &'a str>>(iter: T) -> Self {
+        let mut iter = iter.iterate();
+        let mut string = String::new();
+        while let Some(s) = iter.next() {
+            string.push_str(s);
+        }
+        string
+    }
+}
+
+impl<K, V, S> Collect<(K, V)> for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn collect<T: Iterate<Item = (K, V)>>(iter: T) -> Self {
+        let mut iter = iter.iterate();
+        let mut map = HashMap::with_capacity_and_hasher(iter.size_hint().0, S::default());
+        while let Some((k, v)) = iter.next() {
+            map.insert(k, v);
+        }
+        map
+    }
+}
+
+impl<K: Ord, V> Collect<(K, V)> for BTreeMap<K, V> {
+    fn collect<T: Iterate<Item = (K, V)>>(iter: T) -> Self {
+        let mut iter = iter.iterate();
+        let mut map = BTreeMap::new();
+        while let Some((k, v)) = iter.next() {
+            map.insert(k, v);
+        }
+        map
+    }
+}
+
+impl<A, S> Collect<A> for HashSet<A, S>
+where
+    A: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn collect<T: Iterate<Item = A>>(iter: T) -> Self {
+        let mut iter = iter.iterate();
+        let mut set = HashSet::with_capacity_and_hasher(iter.size_hint().0, S::default());
+        while let Some(item) = iter.next() {
+            set.insert(item);
+        }
+        set
+    }
+}
+
+impl<A: Ord> Collect<A> for BTreeSet<A> {
+    fn collect<T: Iterate<Item = A>>(iter: T) -> Self {
+        let mut iter = iter.iterate();
+        let mut set = BTreeSet::new();
+        while let Some(item) = iter.next() {
+            set.insert(item);
+        }
+        set
+    }
+}
+
+impl<A, E, V: Collect<A>> Collect<Result<A, E>> for Result<V, E> {
+    fn collect<T: Iterate<Item = Result<A, E>>>(iter: T) -> Self {
+        try_process(iter.iterate(), |shunt| V::collect(shunt))
+    }
+}
+
+impl<A, V: Collect<A>> Collect<Option<A>> for Option<V> {
+    fn collect<T: Iterate<Item = Option<A>>>(iter: T) -> Self {
+        let iter = iter.map(|item| item.ok_or(())).iterate();
+        try_process(iter, |shunt| V::collect(shunt)).ok()
+    }
+}
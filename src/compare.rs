@@ -0,0 +1,24 @@
+//! Helpers for comparing the items of two iterators
+
+use super::Iterator;
+use std::cmp::Ordering;
+
+/// Lexicographically compare the items of two iterators.
+pub(crate) fn partial_cmp<A, B>(mut a: A, mut b: B) -> Option<Ordering>
+where
+    A: Iterator,
+    B: Iterator,
+    A::Item: PartialOrd<B::Item>,
+{
+    loop {
+        match (a.next(), b.next()) {
+            (None, None) => return Some(Ordering::Equal),
+            (None, Some(_)) => return Some(Ordering::Less),
+            (Some(_), None) => return Some(Ordering::Greater),
+            (Some(x), Some(y)) => match x.partial_cmp(&y) {
+                Some(Ordering::Equal) => {}
+                non_eq => return non_eq,
+            },
+        }
+    }
+}
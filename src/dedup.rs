@@ -0,0 +1,231 @@
+//! Helper types for the `dedup`, `dedup_by` and `dedup_by_key` operations
+
+use super::{FusedIterator, Iterate, Iterator};
+
+/// Computes the size hint of an iterator which may drop any item after the
+/// first.
+fn size_hint<I: Iterator>(iter: &I, started: bool) -> (usize, Option<usize>) {
+    let (lower, upper) = iter.size_hint();
+    let lower = if started { 0 } else { lower.min(1) };
+    (lower, upper)
+}
+
+/// An iterator which removes consecutive equal items
+#[derive(Debug)]
+pub struct Dedup<I: Iterator> {
+    iter: I,
+    last: Option<I::Item>,
+}
+
+impl<I: Iterator> Dedup<I> {
+    fn new(iter: I) -> Dedup<I> {
+        Dedup { iter, last: None }
+    }
+}
+
+/// A type that can be converted into a dedup iterator.
+#[derive(Debug, Clone)]
+pub struct IntoDedup<I> {
+    iter: I,
+}
+
+impl<I> IntoDedup<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter }
+    }
+}
+
+/// An iterator which removes consecutive items that a function considers
+/// equal
+#[derive(Debug)]
+pub struct DedupBy<I: Iterator, F> {
+    iter: I,
+    same: F,
+    last: Option<I::Item>,
+}
+
+impl<I: Iterator, F> DedupBy<I, F> {
+    fn new(iter: I, same: F) -> DedupBy<I, F> {
+        DedupBy {
+            iter,
+            same,
+            last: None,
+        }
+    }
+}
+
+/// A type that can be converted into a dedup-by iterator.
+#[derive(Debug, Clone)]
+pub struct IntoDedupBy<I, F> {
+    iter: I,
+    same: F,
+}
+
+impl<I, F> IntoDedupBy<I, F> {
+    pub(crate) fn new(iter: I, same: F) -> Self {
+        Self { iter, same }
+    }
+}
+
+/// An iterator which removes consecutive items that map to the same key
+#[derive(Debug)]
+pub struct DedupByKey<I, F, K> {
+    iter: I,
+    key: F,
+    last: Option<K>,
+}
+
+impl<I, F, K> DedupByKey<I, F, K> {
+    fn new(iter: I, key: F) -> DedupByKey<I, F, K> {
+        DedupByKey {
+            iter,
+            key,
+            last: None,
+        }
+    }
+}
+
+/// A type that can be converted into a dedup-by-key iterator.
+#[derive(Debug, Clone)]
+pub struct IntoDedupByKey<I, F> {
+    iter: I,
+    key: F,
+}
+
+impl<I, F> IntoDedupByKey<I, F> {
+    pub(crate) fn new(iter: I, key: F) -> Self {
+        Self { iter, key }
+    }
+}
+
+impl<I: Iterator> Iterator for Dedup<I>
+where
+    I::Item: PartialEq + Clone,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        loop {
+            let item = self.iter.next()?;
+            if self.last.as_ref() != Some(&item) {
+                self.last = Some(item.clone());
+                return Some(item);
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint(&self.iter, self.last.is_some())
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for Dedup<I> where I::Item: PartialEq + Clone {}
+
+impl<I: Iterate> Iterate for IntoDedup<I>
+where
+    I::Item: PartialEq + Clone,
+{
+    type Item = I::Item;
+
+    type Iterator = Dedup<I::Iterator>;
+
+    fn iterate(self) -> Self::Iterator {
+        Dedup::new(self.iter.iterate())
+    }
+}
+
+impl<I: Iterator, F> Iterator for DedupBy<I, F>
+where
+    I::Item: Clone,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        loop {
+            let item = self.iter.next()?;
+            match &self.last {
+                Some(last) if (self.same)(last, &item) => continue,
+                _ => {
+                    self.last = Some(item.clone());
+                    return Some(item);
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint(&self.iter, self.last.is_some())
+    }
+}
+
+impl<I: FusedIterator, F> FusedIterator for DedupBy<I, F>
+where
+    I::Item: Clone,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+}
+
+impl<I: Iterate, F> Iterate for IntoDedupBy<I, F>
+where
+    I::Item: Clone,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    type Iterator = DedupBy<I::Iterator, F>;
+
+    fn iterate(self) -> Self::Iterator {
+        DedupBy::new(self.iter.iterate(), self.same)
+    }
+}
+
+impl<I: Iterator, F, K> Iterator for DedupByKey<I, F, K>
+where
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        loop {
+            let item = self.iter.next()?;
+            let key = (self.key)(&item);
+            if self.last.as_ref() != Some(&key) {
+                self.last = Some(key);
+                return Some(item);
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint(&self.iter, self.last.is_some())
+    }
+}
+
+impl<I: FusedIterator, F, K> FusedIterator for DedupByKey<I, F, K>
+where
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+}
+
+impl<I: Iterate, F, K> Iterate for IntoDedupByKey<I, F>
+where
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = I::Item;
+
+    type Iterator = DedupByKey<I::Iterator, F, K>;
+
+    fn iterate(self) -> Self::Iterator {
+        DedupByKey::new(self.iter.iterate(), self.key)
+    }
+}
@@ -0,0 +1,62 @@
+//! `ExtendCollect` implementations for `std` types
+
+use super::{ExtendCollect, Iterate, Iterator};
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, Hash};
+
+impl<A> ExtendCollect<A> for Vec<A> {
+    fn extend_collect<T: Iterate<Item = A>>(&mut self, iter: T) {
+        let mut iter = iter.iterate();
+        self.reserve(iter.size_hint().0);
+        while let Some(item) = iter.next() {
+            self.push(item);
+        }
+    }
+}
+
+impl ExtendCollect<char> for String {
+    fn extend_collect<T: Iterate<Item = char>>(&mut self, iter: T) {
+        let mut iter = iter.iterate();
+        self.reserve(iter.size_hint().0);
+        while let Some(c) = iter.next() {
+            self.push(c);
+        }
+    }
+}
+
+impl<'a> ExtendCollect<&'a str> for String {
+    fn extend_collect<T: Iterate<Item = &'a str>>(&mut self, iter: T) {
+        let mut iter = iter.iterate();
+        while let Some(s) = iter.next() {
+            self.push_str(s);
+        }
+    }
+}
+
+impl<K, V, S> ExtendCollect<(K, V)> for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn extend_collect<T: Iterate<Item = (K, V)>>(&mut self, iter: T) {
+        let mut iter = iter.iterate();
+        self.reserve(iter.size_hint().0);
+        while let Some((k, v)) = iter.next() {
+            self.insert(k, v);
+        }
+    }
+}
+
+impl<A, S> ExtendCollect<A> for HashSet<A, S>
+where
+    A: Eq + Hash,
+    S: BuildHasher,
+{
+    fn extend_collect<T: Iterate<Item = A>>(&mut self, iter: T) {
+        let mut iter = iter.iterate();
+        self.reserve(iter.size_hint().0);
+        while let Some(item) = iter.next() {
+            self.insert(item);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Helper types for the `filter_map` operation
+
+use super::{DoubleEndedIterator, FusedIterator, Iterate, Iterator};
+
+/// An iterator which both filters and maps items
+#[derive(Debug)]
+pub struct FilterMap<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> FilterMap<I, F> {
+    fn new(iter: I, f: F) -> FilterMap<I, F> {
+        FilterMap { iter, f }
+    }
+}
+
+/// A type that can be converted into a filter-map iterator.
+#[derive(Debug, Clone)]
+pub struct IntoFilterMap<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> IntoFilterMap<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { iter, f }
+    }
+}
+
+impl<B, I: Iterator, F> Iterator for FilterMap<I, F>
+where
+    F: FnMut(I::Item) -> Option<B>,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<B> {
+        while let Some(item) = self.iter.next() {
+            if let Some(item) = (self.f)(item) {
+                return Some(item);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<B, I: DoubleEndedIterator, F> DoubleEndedIterator for FilterMap<I, F>
+where
+    F: FnMut(I::Item) -> Option<B>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<B> {
+        while let Some(item) = self.iter.next_back() {
+            if let Some(item) = (self.f)(item) {
+                return Some(item);
+            }
+        }
+        None
+    }
+}
+
+impl<B, I: FusedIterator, F> FusedIterator for FilterMap<I, F> where F: FnMut(I::Item) -> Option<B> {}
+
+impl<B, I: Iterate, F> Iterate for IntoFilterMap<I, F>
+where
+    F: FnMut(I::Item) -> Option<B>,
+{
+    type Item = B;
+
+    type Iterator = FilterMap<I::Iterator, F>;
+
+    fn iterate(self) -> Self::Iterator {
+        FilterMap::new(self.iter.iterate(), self.f)
+    }
+}
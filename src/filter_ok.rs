@@ -0,0 +1,89 @@
+//! Helper types for the `filter_ok` operation
+
+use super::{DoubleEndedIterator, FusedIterator, Iterate, Iterator};
+
+/// An iterator which filters the `Ok` values of `Result` items
+#[derive(Debug)]
+pub struct FilterOk<I, P> {
+    iter: I,
+    predicate: P,
+}
+
+impl<I, P> FilterOk<I, P> {
+    fn new(iter: I, predicate: P) -> FilterOk<I, P> {
+        FilterOk { iter, predicate }
+    }
+}
+
+/// A type that can be converted into a filter-ok iterator.
+#[derive(Debug, Clone)]
+pub struct IntoFilterOk<I, P> {
+    iter: I,
+    predicate: P,
+}
+
+impl<I, P> IntoFilterOk<I, P> {
+    pub(crate) fn new(iter: I, predicate: P) -> Self {
+        Self { iter, predicate }
+    }
+}
+
+impl<T, E, I, P> Iterator for FilterOk<I, P>
+where
+    I: Iterator<Item = Result<T, E>>,
+    P: FnMut(&T) -> bool,
+{
+    type Item = Result<T, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<T, E>> {
+        loop {
+            match self.iter.next()? {
+                Ok(item) if !(self.predicate)(&item) => continue,
+                item => return Some(item),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<T, E, I, P> DoubleEndedIterator for FilterOk<I, P>
+where
+    I: DoubleEndedIterator<Item = Result<T, E>>,
+    P: FnMut(&T) -> bool,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Result<T, E>> {
+        loop {
+            match self.iter.next_back()? {
+                Ok(item) if !(self.predicate)(&item) => continue,
+                item => return Some(item),
+            }
+        }
+    }
+}
+
+impl<T, E, I, P> FusedIterator for FilterOk<I, P>
+where
+    I: FusedIterator<Item = Result<T, E>>,
+    P: FnMut(&T) -> bool,
+{
+}
+
+impl<T, E, I, P> Iterate for IntoFilterOk<I, P>
+where
+    I: Iterate<Item = Result<T, E>>,
+    P: FnMut(&T) -> bool,
+{
+    type Item = Result<T, E>;
+
+    type Iterator = FilterOk<I::Iterator, P>;
+
+    fn iterate(self) -> Self::Iterator {
+        FilterOk::new(self.iter.iterate(), self.predicate)
+    }
+}
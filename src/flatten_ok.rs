@@ -0,0 +1,85 @@
+//! Helper types for the `flatten_ok` operation
+
+use super::{FusedIterator, Iterate, Iterator};
+
+/// An iterator which flattens the `Ok` values of `Result` items
+#[derive(Debug)]
+pub struct FlattenOk<I, J> {
+    iter: I,
+    inner: Option<J>,
+}
+
+impl<I, J> FlattenOk<I, J> {
+    fn new(iter: I) -> FlattenOk<I, J> {
+        FlattenOk { iter, inner: None }
+    }
+}
+
+/// A type that can be converted into a flatten-ok iterator.
+#[derive(Debug, Clone)]
+pub struct IntoFlattenOk<I> {
+    iter: I,
+}
+
+impl<I> IntoFlattenOk<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter }
+    }
+}
+
+impl<J, E, I> Iterator for FlattenOk<I, J::Iterator>
+where
+    I: Iterator<Item = Result<J, E>>,
+    J: Iterate,
+{
+    type Item = Result<J::Item, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<J::Item, E>> {
+        loop {
+            if let Some(inner) = &mut self.inner {
+                if let Some(item) = inner.next() {
+                    return Some(Ok(item));
+                }
+                self.inner = None;
+            }
+            match self.iter.next()? {
+                Ok(inner) => self.inner = Some(inner.iterate()),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = match &self.inner {
+            Some(inner) => inner.size_hint(),
+            None => (0, Some(0)),
+        };
+        match self.iter.size_hint() {
+            (_, Some(0)) => (lower, upper),
+            _ => (lower, None),
+        }
+    }
+}
+
+impl<J, E, I> FusedIterator for FlattenOk<I, J::Iterator>
+where
+    I: FusedIterator<Item = Result<J, E>>,
+    J: Iterate,
+{
+}
+
+impl<J, E, I> Iterate for IntoFlattenOk<I>
+where
+    I: Iterate<Item = Result<J, E>>,
+    J: Iterate,
+{
+    type Item = Result<J::Item, E>;
+
+    type Iterator = FlattenOk<I::Iterator, J::Iterator>;
+
+    fn iterate(self) -> Self::Iterator {
+        FlattenOk::new(self.iter.iterate())
+    }
+}
@@ -0,0 +1,54 @@
+//! Helper types for the `format` operation
+
+use super::{Iterate, Iterator};
+use std::cell::Cell;
+use std::fmt;
+
+/// A value which formats the items of an iterator with a separator
+///
+/// The items are written straight to the formatter without allocating.
+/// Because formatting consumes the iterator, a `Format` can only be
+/// formatted once.
+pub struct Format<'a, I> {
+    iter: Cell<Option<I>>,
+    sep: &'a str,
+}
+
+impl<'a, I> Format<'a, I> {
+    pub(crate) fn new(iter: I, sep: &'a str) -> Self {
+        Self {
+            iter: Cell::new(Some(iter)),
+            sep,
+        }
+    }
+}
+
+impl<I> fmt::Debug for Format<'_, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Format")
+            .field("sep", &self.sep)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I> fmt::Display for Format<'_, I>
+where
+    I: Iterate,
+    I::Item: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut iter = self
+            .iter
+            .take()
+            .expect("`Format` can only be formatted once")
+            .iterate();
+        if let Some(first) = iter.next() {
+            fmt::Display::fmt(&first, f)?;
+        }
+        while let Some(item) = iter.next() {
+            f.write_str(self.sep)?;
+            fmt::Display::fmt(&item, f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+//! Helper types for the `fuse` operation
+
+use super::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterate, Iterator};
+
+/// An iterator which yields `None` forever after the underlying iterator
+/// yields `None` once
+#[derive(Debug)]
+pub struct Fuse<I> {
+    iter: I,
+    done: bool,
+}
+
+impl<I> Fuse<I> {
+    fn new(iter: I) -> Fuse<I> {
+        Fuse { iter, done: false }
+    }
+}
+
+/// A type that can be converted into a fused iterator.
+#[derive(Debug, Clone)]
+pub struct IntoFuse<I> {
+    iter: I,
+}
+
+impl<I> IntoFuse<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter }
+    }
+}
+
+impl<I: Iterator> Iterator for Fuse<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        if self.done {
+            return None;
+        }
+        let item = self.iter.next();
+        if item.is_none() {
+            self.done = true;
+        }
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for Fuse<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Item> {
+        if self.done {
+            return None;
+        }
+        let item = self.iter.next_back();
+        if item.is_none() {
+            self.done = true;
+        }
+        item
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for Fuse<I> {
+    #[inline]
+    fn len(&self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.iter.len()
+        }
+    }
+}
+
+impl<I: Iterator> FusedIterator for Fuse<I> {}
+
+impl<I: Iterate> Iterate for IntoFuse<I> {
+    type Item = I::Item;
+
+    type Iterator = Fuse<I::Iterator>;
+
+    fn iterate(self) -> Self::Iterator {
+        Fuse::new(self.iter.iterate())
+    }
+}
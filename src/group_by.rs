@@ -0,0 +1,93 @@
+//! Helper types for the `group_by` operation
+
+use super::{FusedIterator, Iterate, Iterator};
+
+/// An iterator which groups consecutive items that map to the same key
+#[derive(Debug)]
+pub struct GroupBy<I: Iterator, F, K> {
+    iter: I,
+    key: F,
+    pending: Option<(K, I::Item)>,
+}
+
+impl<I: Iterator, F, K> GroupBy<I, F, K> {
+    fn new(iter: I, key: F) -> GroupBy<I, F, K> {
+        GroupBy {
+            iter,
+            key,
+            pending: None,
+        }
+    }
+}
+
+/// A type that can be converted into a group-by iterator.
+#[derive(Debug, Clone)]
+pub struct IntoGroupBy<I, F> {
+    iter: I,
+    key: F,
+}
+
+impl<I, F> IntoGroupBy<I, F> {
+    pub(crate) fn new(iter: I, key: F) -> Self {
+        Self { iter, key }
+    }
+}
+
+impl<I: Iterator, F, K> Iterator for GroupBy<I, F, K>
+where
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = (K, Vec<I::Item>);
+
+    #[inline]
+    fn next(&mut self) -> Option<(K, Vec<I::Item>)> {
+        let (key, first) = match self.pending.take() {
+            Some(pending) => pending,
+            None => {
+                let item = self.iter.next()?;
+                ((self.key)(&item), item)
+            }
+        };
+        let mut group = vec![first];
+        while let Some(item) = self.iter.next() {
+            let next_key = (self.key)(&item);
+            if next_key == key {
+                group.push(item);
+            } else {
+                self.pending = Some((next_key, item));
+                break;
+            }
+        }
+        Some((key, group))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let pending = self.pending.is_some() as usize;
+        let lower = lower.saturating_add(pending).min(1);
+        (lower, upper.and_then(|upper| upper.checked_add(pending)))
+    }
+}
+
+impl<I: FusedIterator, F, K> FusedIterator for GroupBy<I, F, K>
+where
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+}
+
+impl<I: Iterate, F, K> Iterate for IntoGroupBy<I, F>
+where
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = (K, Vec<I::Item>);
+
+    type Iterator = GroupBy<I::Iterator, F, K>;
+
+    fn iterate(self) -> Self::Iterator {
+        GroupBy::new(self.iter.iterate(), self.key)
+    }
+}
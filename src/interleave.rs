@@ -0,0 +1,85 @@
+//! Helper types for the `interleave` operation
+
+use super::{FusedIterator, Iterate, Iterator};
+
+/// An iterator which alternates between the items of two iterators
+#[derive(Debug)]
+pub struct Interleave<A, B> {
+    a: A,
+    b: B,
+    next_is_a: bool,
+}
+
+impl<A, B> Interleave<A, B> {
+    fn new(a: A, b: B) -> Interleave<A, B> {
+        Interleave {
+            a,
+            b,
+            next_is_a: true,
+        }
+    }
+}
+
+/// A type that can be converted into an interleaving iterator.
+#[derive(Debug, Clone)]
+pub struct IntoInterleave<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> IntoInterleave<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<A, B> Iterator for Interleave<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<A::Item> {
+        let item = if self.next_is_a {
+            self.a.next().or_else(|| self.b.next())
+        } else {
+            self.b.next().or_else(|| self.a.next())
+        };
+        self.next_is_a = !self.next_is_a;
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        (a_lower.saturating_add(b_lower), upper)
+    }
+}
+
+impl<A, B> FusedIterator for Interleave<A, B>
+where
+    A: FusedIterator,
+    B: FusedIterator<Item = A::Item>,
+{
+}
+
+impl<A, B> Iterate for IntoInterleave<A, B>
+where
+    A: Iterate,
+    B: Iterate<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    type Iterator = Interleave<A::Iterator, B::Iterator>;
+
+    fn iterate(self) -> Self::Iterator {
+        Interleave::new(self.a.iterate(), self.b.iterate())
+    }
+}
@@ -0,0 +1,168 @@
+//! Helper types for the `intersperse` and `intersperse_with` operations
+
+use super::{FusedIterator, Iterate, Iterator};
+
+/// An iterator which places a clone of a separator between adjacent items
+#[derive(Debug)]
+pub struct Intersperse<I: Iterator> {
+    iter: I,
+    separator: I::Item,
+    next_item: Option<I::Item>,
+    started: bool,
+}
+
+impl<I: Iterator> Intersperse<I> {
+    fn new(iter: I, separator: I::Item) -> Intersperse<I> {
+        Intersperse {
+            iter,
+            separator,
+            next_item: None,
+            started: false,
+        }
+    }
+}
+
+/// A type that can be converted into an interspersing iterator.
+#[derive(Debug, Clone)]
+pub struct IntoIntersperse<I: Iterate> {
+    iter: I,
+    separator: I::Item,
+}
+
+impl<I: Iterate> IntoIntersperse<I> {
+    pub(crate) fn new(iter: I, separator: I::Item) -> Self {
+        Self { iter, separator }
+    }
+}
+
+/// An iterator which places a generated separator between adjacent items
+#[derive(Debug)]
+pub struct IntersperseWith<I: Iterator, G> {
+    iter: I,
+    separator: G,
+    next_item: Option<I::Item>,
+    started: bool,
+}
+
+impl<I: Iterator, G> IntersperseWith<I, G> {
+    fn new(iter: I, separator: G) -> IntersperseWith<I, G> {
+        IntersperseWith {
+            iter,
+            separator,
+            next_item: None,
+            started: false,
+        }
+    }
+}
+
+/// A type that can be converted into an interspersing iterator with
+/// generated separators.
+#[derive(Debug, Clone)]
+pub struct IntoIntersperseWith<I, G> {
+    iter: I,
+    separator: G,
+}
+
+impl<I, G> IntoIntersperseWith<I, G> {
+    pub(crate) fn new(iter: I, separator: G) -> Self {
+        Self { iter, separator }
+    }
+}
+
+/// Computes the number of items left after interspersing `n` source items.
+fn interspersed_len(n: usize, started: bool, pending: bool) -> Option<usize> {
+    let n = match (n.checked_mul(2)?, started) {
+        (n, true) => n,
+        (n, false) => n.saturating_sub(1),
+    };
+    n.checked_add(pending as usize)
+}
+
+fn size_hint<I: Iterator>(iter: &I, started: bool, pending: bool) -> (usize, Option<usize>) {
+    let (lower, upper) = iter.size_hint();
+    let lower = interspersed_len(lower, started, pending).unwrap_or(usize::MAX);
+    let upper = upper.and_then(|upper| interspersed_len(upper, started, pending));
+    (lower, upper)
+}
+
+impl<I: Iterator> Iterator for Intersperse<I>
+where
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        if !self.started {
+            self.started = true;
+            return self.iter.next();
+        }
+        if let Some(item) = self.next_item.take() {
+            return Some(item);
+        }
+        let item = self.iter.next()?;
+        self.next_item = Some(item);
+        Some(self.separator.clone())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint(&self.iter, self.started, self.next_item.is_some())
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for Intersperse<I> where I::Item: Clone {}
+
+impl<I: Iterate> Iterate for IntoIntersperse<I>
+where
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    type Iterator = Intersperse<I::Iterator>;
+
+    fn iterate(self) -> Self::Iterator {
+        Intersperse::new(self.iter.iterate(), self.separator)
+    }
+}
+
+impl<I: Iterator, G> Iterator for IntersperseWith<I, G>
+where
+    G: FnMut() -> I::Item,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        if !self.started {
+            self.started = true;
+            return self.iter.next();
+        }
+        if let Some(item) = self.next_item.take() {
+            return Some(item);
+        }
+        let item = self.iter.next()?;
+        self.next_item = Some(item);
+        Some((self.separator)())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint(&self.iter, self.started, self.next_item.is_some())
+    }
+}
+
+impl<I: FusedIterator, G> FusedIterator for IntersperseWith<I, G> where G: FnMut() -> I::Item {}
+
+impl<I: Iterate, G> Iterate for IntoIntersperseWith<I, G>
+where
+    G: FnMut() -> I::Item,
+{
+    type Item = I::Item;
+
+    type Iterator = IntersperseWith<I::Iterator, G>;
+
+    fn iterate(self) -> Self::Iterator {
+        IntersperseWith::new(self.iter.iterate(), self.separator)
+    }
+}
@@ -19,7 +19,7 @@
 //! Here's a practical case people are bound to hit when writing generator
 //! blocks, which can't be fixed unless generator returns `IntoIterator`:
 //!
-//! ```rust
+//! ```ignore
 //! // A gen block that holds some `!Send` type across a yield point
 //! let iter = gen {
 //!     let items = my_data.lock(); // ← `MutexGuard: !Send`
@@ -71,7 +71,54 @@
 #![deny(missing_debug_implementations, nonstandard_style)]
 #![warn(missing_docs, future_incompatible, unreachable_pub)]
 
+pub mod array;
+pub mod array_chunks;
+mod buffer;
+pub mod by_ref;
+pub mod cartesian_product;
+pub mod chunks;
+pub mod coalesce;
+mod collect;
+mod compare;
+pub mod dedup;
+mod extend;
+pub mod filter_map;
+pub mod filter_ok;
+pub mod flatten_ok;
+pub mod format;
+pub mod fuse;
+pub mod group_by;
+pub mod interleave;
+pub mod intersperse;
 pub mod map;
+pub mod map_err;
+pub mod map_ok;
+pub mod map_while;
+pub mod merge;
+pub mod multipeek;
+pub mod ok;
+pub mod option;
+pub mod pad_using;
+pub mod positions;
+mod product;
+pub mod range;
+pub mod result;
+pub mod rev;
+mod shunt;
+pub mod skip_last;
+pub mod slice;
+pub mod sorted;
+pub mod sources;
+pub mod split;
+mod sum;
+pub mod take_while_inclusive;
+pub mod tee;
+pub mod update;
+pub mod vec;
+pub mod while_some;
+pub mod windows;
+pub mod with_position;
+pub mod zip_longest;
 
 /// A stateful iterator returned by [`Iterate::iterate`].
 pub trait Iterator {
@@ -85,8 +132,62 @@ pub trait Iterator {
     fn size_hint(&self) -> (usize, Option<usize>) {
         (0, None)
     }
+
+    /// Borrows the iterator, so it can be passed to [`Iterate`] operations
+    /// without being consumed.
+    ///
+    /// Once the borrow ends, iteration can resume where it left off.
+    fn by_ref(&mut self) -> by_ref::ByRef<'_, Self> {
+        by_ref::ByRef::new(self)
+    }
 }
 
+impl<I: Iterator + ?Sized> Iterator for &mut I {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (**self).next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+}
+
+/// An iterator able to yield elements from both ends.
+pub trait DoubleEndedIterator: Iterator {
+    /// Removes and returns an element from the end of the iterator.
+    fn next_back(&mut self) -> Option<Self::Item>;
+}
+
+impl<I: DoubleEndedIterator + ?Sized> DoubleEndedIterator for &mut I {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        (**self).next_back()
+    }
+}
+
+/// An iterator that knows its exact length.
+pub trait ExactSizeIterator: Iterator {
+    /// Returns the exact remaining length of the iterator.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the iterator has no more items.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<I: ExactSizeIterator + ?Sized> ExactSizeIterator for &mut I {
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
+
+/// An iterator that always continues to yield `None` once exhausted.
+pub trait FusedIterator: Iterator {}
+
+impl<I: FusedIterator + ?Sized> FusedIterator for &mut I {}
+
 /// Provide sequential, iterated access to items.
 pub trait Iterate {
     /// The type of the elements being iterated over.
@@ -99,14 +200,934 @@ pub trait Iterate {
     fn iterate(self) -> Self::Iterator;
 
     /// Maps the values of iter with f.
+    ///
+    /// `f` is called once for every item, so it has to be `FnMut`.
     fn map<F, B>(self, f: F) -> map::IntoMap<Self, F>
     where
-        F: FnOnce(Self::Item) -> B,
+        F: FnMut(Self::Item) -> B,
         Self: Sized,
     {
         map::IntoMap::new(self, f)
     }
 
+    /// Maps the `Err` values of `Result` items with f, passing `Ok` values
+    /// through untouched.
+    fn map_err<T, E, U, F>(self, f: F) -> map_err::IntoMapErr<Self, F>
+    where
+        F: FnMut(E) -> U,
+        Self: Iterate<Item = Result<T, E>> + Sized,
+    {
+        map_err::IntoMapErr::new(self, f)
+    }
+
+    /// Maps the `Ok` values of `Result` items with f, passing `Err` values
+    /// through untouched.
+    fn map_ok<T, E, U, F>(self, f: F) -> map_ok::IntoMapOk<Self, F>
+    where
+        F: FnMut(T) -> U,
+        Self: Iterate<Item = Result<T, E>> + Sized,
+    {
+        map_ok::IntoMapOk::new(self, f)
+    }
+
+    /// Maps the values of iter with f until f returns `None`.
+    fn map_while<F, B>(self, f: F) -> map_while::IntoMapWhile<Self, F>
+    where
+        F: FnMut(Self::Item) -> Option<B>,
+        Self: Sized,
+    {
+        map_while::IntoMapWhile::new(self, f)
+    }
+
+    /// Removes consecutive equal items.
+    ///
+    /// Only adjacent duplicates are removed; to remove all duplicates, sort the
+    /// items first.
+    fn dedup(self) -> dedup::IntoDedup<Self>
+    where
+        Self::Item: PartialEq + Clone,
+        Self: Sized,
+    {
+        dedup::IntoDedup::new(self)
+    }
+
+    /// Removes consecutive items which `same` considers equal.
+    ///
+    /// `same` is called with the last yielded item and the next candidate.
+    fn dedup_by<F>(self, same: F) -> dedup::IntoDedupBy<Self, F>
+    where
+        Self::Item: Clone,
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+        Self: Sized,
+    {
+        dedup::IntoDedupBy::new(self, same)
+    }
+
+    /// Removes consecutive items which map to the same key.
+    fn dedup_by_key<K, F>(self, key: F) -> dedup::IntoDedupByKey<Self, F>
+    where
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+        Self: Sized,
+    {
+        dedup::IntoDedupByKey::new(self, key)
+    }
+
+    /// Filters the `Ok` values of `Result` items with `predicate`, passing
+    /// `Err` values through untouched.
+    fn filter_ok<T, E, P>(self, predicate: P) -> filter_ok::IntoFilterOk<Self, P>
+    where
+        P: FnMut(&T) -> bool,
+        Self: Iterate<Item = Result<T, E>> + Sized,
+    {
+        filter_ok::IntoFilterOk::new(self, predicate)
+    }
+
+    /// Filters and maps the values of iter with f, skipping every `None`.
+    fn filter_map<F, B>(self, f: F) -> filter_map::IntoFilterMap<Self, F>
+    where
+        F: FnMut(Self::Item) -> Option<B>,
+        Self: Sized,
+    {
+        filter_map::IntoFilterMap::new(self, f)
+    }
+
+    /// Groups items into arrays of `N` items.
+    ///
+    /// Trailing items which don't fill a complete array are discarded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    fn array_chunks<const N: usize>(self) -> array_chunks::IntoArrayChunks<Self, N>
+    where
+        Self: Sized,
+    {
+        array_chunks::IntoArrayChunks::new(self)
+    }
+
+    /// Yields every pair of an item from this iterator and an item from
+    /// `other`.
+    ///
+    /// `other` is cloned and iterated again for every item of this iterator.
+    fn cartesian_product<U>(self, other: U) -> cartesian_product::IntoCartesianProduct<Self, U>
+    where
+        U: Iterate + Clone,
+        Self::Item: Clone,
+        Self: Sized,
+    {
+        cartesian_product::IntoCartesianProduct::new(self, other)
+    }
+
+    /// Groups items into non-overlapping chunks of `size` items.
+    ///
+    /// The last chunk is shorter if the number of items isn't a multiple of
+    /// `size`. No empty chunks are yielded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    fn chunks(self, size: usize) -> chunks::IntoChunks<Self>
+    where
+        Self: Sized,
+    {
+        chunks::IntoChunks::new(self, size)
+    }
+
+    /// Merges adjacent items.
+    ///
+    /// When `f` returns `Ok(merged)` the two items are replaced by `merged`,
+    /// which is then offered for merging with the next item. When `f` returns
+    /// `Err((prev, next))`, `prev` is yielded and `next` is kept for merging.
+    fn coalesce<F>(self, f: F) -> coalesce::IntoCoalesce<Self, F>
+    where
+        F: FnMut(Self::Item, Self::Item) -> Result<Self::Item, (Self::Item, Self::Item)>,
+        Self: Sized,
+    {
+        coalesce::IntoCoalesce::new(self, f)
+    }
+
+    /// Flattens the `Ok` values of `Result` items, passing `Err` values
+    /// through untouched.
+    fn flatten_ok<J, E>(self) -> flatten_ok::IntoFlattenOk<Self>
+    where
+        J: Iterate,
+        Self: Iterate<Item = Result<J, E>> + Sized,
+    {
+        flatten_ok::IntoFlattenOk::new(self)
+    }
+
+    /// Creates an iterator which ends after the first `None`.
+    fn fuse(self) -> fuse::IntoFuse<Self>
+    where
+        Self: Sized,
+    {
+        fuse::IntoFuse::new(self)
+    }
+
+    /// Groups consecutive items which map to the same key.
+    ///
+    /// Yields each key along with its group of items. Only consecutive runs
+    /// are grouped; to group all items by key, sort them first.
+    fn group_by<K, F>(self, key: F) -> group_by::IntoGroupBy<Self, F>
+    where
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+        Self: Sized,
+    {
+        group_by::IntoGroupBy::new(self, key)
+    }
+
+    /// Alternates between the items of this iterator and `other`.
+    ///
+    /// Once either iterator is exhausted, the remaining items of the other are
+    /// yielded.
+    fn interleave<U>(self, other: U) -> interleave::IntoInterleave<Self, U>
+    where
+        U: Iterate<Item = Self::Item>,
+        Self: Sized,
+    {
+        interleave::IntoInterleave::new(self, other)
+    }
+
+    /// Places a clone of `separator` between adjacent items.
+    fn intersperse(self, separator: Self::Item) -> intersperse::IntoIntersperse<Self>
+    where
+        Self::Item: Clone,
+        Self: Sized,
+    {
+        intersperse::IntoIntersperse::new(self, separator)
+    }
+
+    /// Places an item generated by `separator` between adjacent items.
+    fn intersperse_with<G>(self, separator: G) -> intersperse::IntoIntersperseWith<Self, G>
+    where
+        G: FnMut() -> Self::Item,
+        Self: Sized,
+    {
+        intersperse::IntoIntersperseWith::new(self, separator)
+    }
+
+    /// Merges this sorted iterator with another sorted iterator, yielding all
+    /// items in sorted order.
+    ///
+    /// When two items are equal, the item from `self` is yielded first.
+    fn merge<U>(self, other: U) -> merge::IntoMerge<Self, U>
+    where
+        U: Iterate<Item = Self::Item>,
+        Self::Item: Ord,
+        Self: Sized,
+    {
+        merge::IntoMerge::new(self, other)
+    }
+
+    /// Creates an iterator which can peek an arbitrary number of items ahead.
+    fn multipeek(self) -> multipeek::IntoMultiPeek<Self>
+    where
+        Self: Sized,
+    {
+        multipeek::IntoMultiPeek::new(self)
+    }
+
+    /// Yields the `Ok` values of `Result` items, skipping every `Err`.
+    fn ok<T, E>(self) -> ok::IntoOkValues<Self>
+    where
+        Self: Iterate<Item = Result<T, E>> + Sized,
+    {
+        ok::IntoOkValues::new(self)
+    }
+
+    /// Pads the iterator to at least `min` items.
+    ///
+    /// Once the source is exhausted, `f` is called with the index of each
+    /// missing item until `min` items have been yielded.
+    fn pad_using<F>(self, min: usize, f: F) -> pad_using::IntoPadUsing<Self, F>
+    where
+        F: FnMut(usize) -> Self::Item,
+        Self: Sized,
+    {
+        pad_using::IntoPadUsing::new(self, min, f)
+    }
+
+    /// Yields the indices of the items which satisfy `predicate`.
+    fn positions<P>(self, predicate: P) -> positions::IntoPositions<Self, P>
+    where
+        P: FnMut(Self::Item) -> bool,
+        Self: Sized,
+    {
+        positions::IntoPositions::new(self, predicate)
+    }
+
+    /// Skips the last `n` items.
+    ///
+    /// Up to `n` items are buffered, so the end of the source can be detected.
+    fn skip_last(self, n: usize) -> skip_last::IntoSkipLast<Self>
+    where
+        Self: Sized,
+    {
+        skip_last::IntoSkipLast::new(self, n)
+    }
+
+    /// Sorts the items.
+    ///
+    /// No work happens until [`Iterate::iterate`] is called, at which point all
+    /// items are buffered and sorted. The sort is stable.
+    fn sorted(self) -> sorted::IntoSorted<Self>
+    where
+        Self::Item: Ord,
+        Self: Sized,
+    {
+        sorted::IntoSorted::new(self)
+    }
+
+    /// Sorts the items with a comparator function.
+    ///
+    /// No work happens until [`Iterate::iterate`] is called, at which point all
+    /// items are buffered and sorted. The sort is stable.
+    fn sorted_by<F>(self, compare: F) -> sorted::IntoSortedBy<Self, F>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        sorted::IntoSortedBy::new(self, compare)
+    }
+
+    /// Sorts the items by a key extraction function.
+    ///
+    /// The key function is called exactly once per item. No work happens until
+    /// [`Iterate::iterate`] is called. The sort is stable.
+    fn sorted_by_key<K, F>(self, f: F) -> sorted::IntoSortedByKey<Self, F>
+    where
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+        Self: Sized,
+    {
+        sorted::IntoSortedByKey::new(self, f)
+    }
+
+    /// Yields segments of items separated by items matching `predicate`.
+    ///
+    /// Separators are not included in the segments. Like `slice::split`, a
+    /// leading or trailing separator produces an empty segment, as do two
+    /// adjacent separators, and an empty source yields one empty segment.
+    fn split<P>(self, predicate: P) -> split::IntoSplit<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+        Self: Sized,
+    {
+        split::IntoSplit::new(self, predicate)
+    }
+
+    /// Yields items while `predicate` returns `true`, followed by the first
+    /// item for which it returns `false`.
+    fn take_while_inclusive<P>(
+        self,
+        predicate: P,
+    ) -> take_while_inclusive::IntoTakeWhileInclusive<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+        Self: Sized,
+    {
+        take_while_inclusive::IntoTakeWhileInclusive::new(self, predicate)
+    }
+
+    /// Splits this builder into two independent builders.
+    ///
+    /// Both builders can be sent to different threads and iterated
+    /// separately. The builder is cloned rather than buffered, so side
+    /// effects of the source run once for every builder that is iterated.
+    fn tee(self) -> (tee::Tee<Self>, tee::Tee<Self>)
+    where
+        Self: Clone,
+    {
+        (tee::Tee::new(self.clone()), tee::Tee::new(self))
+    }
+
+    /// Mutates each item in place with f before yielding it.
+    fn update<F>(self, f: F) -> update::IntoUpdate<Self, F>
+    where
+        F: FnMut(&mut Self::Item),
+        Self: Sized,
+    {
+        update::IntoUpdate::new(self, f)
+    }
+
+    /// Yields the values of `Option` items, stopping at the first `None`.
+    ///
+    /// No items are pulled from the source after the first `None`.
+    fn while_some<T>(self) -> while_some::IntoWhileSome<Self>
+    where
+        Self: Iterate<Item = Option<T>> + Sized,
+    {
+        while_some::IntoWhileSome::new(self)
+    }
+
+    /// Yields overlapping windows of `size` items, advancing one item at a
+    /// time.
+    ///
+    /// Sources with fewer than `size` items yield no windows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    fn windows(self, size: usize) -> windows::IntoWindows<Self>
+    where
+        Self::Item: Clone,
+        Self: Sized,
+    {
+        windows::IntoWindows::new(self, size)
+    }
+
+    /// Annotates each item with whether it's the first, last, only, or a
+    /// middle item.
+    ///
+    /// One item is buffered ahead to know whether the current item is the
+    /// last.
+    fn with_position(self) -> with_position::IntoWithPosition<Self>
+    where
+        Self: Sized,
+    {
+        with_position::IntoWithPosition::new(self)
+    }
+
+    /// Zips this iterator with `other`, continuing until both are exhausted.
+    ///
+    /// Once the shorter iterator runs out, the remaining items of the longer
+    /// one are yielded on their own.
+    fn zip_longest<U>(self, other: U) -> zip_longest::IntoZipLongest<Self, U>
+    where
+        U: Iterate,
+        Self: Sized,
+    {
+        zip_longest::IntoZipLongest::new(self, other)
+    }
+
+    /// Folds every item into an accumulator by applying an operation,
+    /// returning the final result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iterate_trait::sources::successors;
+    /// use iterate_trait::Iterate;
+    ///
+    /// let numbers = successors(Some(1), |n| if *n < 4 { Some(n + 1) } else { None });
+    /// let sum = numbers.map(|n| n * 10).fold(0, |acc, n| acc + n);
+    /// assert_eq!(sum, 100);
+    /// ```
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+        Self: Sized,
+    {
+        let mut iter = self.iterate();
+        let mut acc = init;
+        while let Some(item) = iter.next() {
+            acc = f(acc, item);
+        }
+        acc
+    }
+
+    /// Calls a closure on each item.
+    ///
+    /// # Examples
+    ///
+    /// Because a pipeline doesn't start until it's iterated, it can be built
+    /// on one thread and drained on another:
+    ///
+    /// ```
+    /// use iterate_trait::sources::successors;
+    /// use iterate_trait::Iterate;
+    /// use std::thread;
+    ///
+    /// let numbers = successors(Some(1), |n| if *n < 3 { Some(n + 1) } else { None });
+    /// let doubled = numbers.map(|n| n * 2);
+    ///
+    /// let handle = thread::spawn(move || {
+    ///     let mut seen = vec![];
+    ///     doubled.for_each(|n| seen.push(n));
+    ///     seen
+    /// });
+    /// assert_eq!(handle.join().unwrap(), vec![2, 4, 6]);
+    /// ```
+    fn for_each<F>(self, mut f: F)
+    where
+        F: FnMut(Self::Item),
+        Self: Sized,
+    {
+        self.fold((), |(), item| f(item));
+    }
+
+    /// Consumes the iterator, counting the number of items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of items overflows a `usize`.
+    fn count(self) -> usize
+    where
+        Self: Sized,
+    {
+        self.fold(0, |count: usize, _| {
+            count
+                .checked_add(1)
+                .expect("overflow when counting the items of an iterator")
+        })
+    }
+
+    /// Consumes the iterator, returning the last item.
+    fn last(self) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        self.fold(None, |_, item| Some(item))
+    }
+
+    /// Consumes the iterator up to and including the `n`th item, returning it.
+    ///
+    /// Counting starts from zero. Returns `None` if the iterator has `n` or
+    /// fewer items.
+    fn nth(self, mut n: usize) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        let mut iter = self.iterate();
+        while let Some(item) = iter.next() {
+            if n == 0 {
+                return Some(item);
+            }
+            n -= 1;
+        }
+        None
+    }
+
+    /// Tests whether every item satisfies `predicate`.
+    ///
+    /// Iteration stops at the first item which doesn't. An empty iterator
+    /// returns `true`.
+    fn all<P>(self, mut predicate: P) -> bool
+    where
+        P: FnMut(Self::Item) -> bool,
+        Self: Sized,
+    {
+        let mut iter = self.iterate();
+        while let Some(item) = iter.next() {
+            if !predicate(item) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Tests whether any item satisfies `predicate`.
+    ///
+    /// Iteration stops at the first item which does. An empty iterator
+    /// returns `false`.
+    fn any<P>(self, mut predicate: P) -> bool
+    where
+        P: FnMut(Self::Item) -> bool,
+        Self: Sized,
+    {
+        let mut iter = self.iterate();
+        while let Some(item) = iter.next() {
+            if predicate(item) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Returns the first item which satisfies `predicate`.
+    ///
+    /// Iteration stops as soon as a matching item is found. Unlike `std`,
+    /// `find` consumes the iterator, so any remaining items are dropped. To
+    /// keep searching afterwards, call it through [`Iterator::by_ref`]:
+    ///
+    /// ```
+    /// use iterate_trait::{Iterate, Iterator};
+    ///
+    /// let mut iter = vec![1, 2, 3, 4].iterate();
+    /// assert_eq!(iter.by_ref().find(|x| x % 2 == 0), Some(2));
+    /// assert_eq!(iter.by_ref().find(|x| x % 2 == 0), Some(4));
+    /// ```
+    fn find<P>(self, mut predicate: P) -> Option<Self::Item>
+    where
+        P: FnMut(&Self::Item) -> bool,
+        Self: Sized,
+    {
+        let mut iter = self.iterate();
+        while let Some(item) = iter.next() {
+            if predicate(&item) {
+                return Some(item);
+            }
+        }
+        None
+    }
+
+    /// Applies `f` to each item, returning the first non-`None` result.
+    ///
+    /// Iteration stops as soon as `f` returns `Some`.
+    fn find_map<B, F>(self, mut f: F) -> Option<B>
+    where
+        F: FnMut(Self::Item) -> Option<B>,
+        Self: Sized,
+    {
+        let mut iter = self.iterate();
+        while let Some(item) = iter.next() {
+            if let Some(item) = f(item) {
+                return Some(item);
+            }
+        }
+        None
+    }
+
+    /// Returns the index of the first item which satisfies `predicate`.
+    ///
+    /// Iteration stops as soon as a matching item is found.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index overflows a `usize`.
+    fn position<P>(self, mut predicate: P) -> Option<usize>
+    where
+        P: FnMut(&Self::Item) -> bool,
+        Self: Sized,
+    {
+        let mut iter = self.iterate();
+        let mut index: usize = 0;
+        while let Some(item) = iter.next() {
+            if predicate(&item) {
+                return Some(index);
+            }
+            index = index
+                .checked_add(1)
+                .expect("overflow when finding the position of an item");
+        }
+        None
+    }
+
+    /// Returns the maximum item.
+    ///
+    /// If several items are equally maximum, the last one is returned.
+    fn max(self) -> Option<Self::Item>
+    where
+        Self::Item: Ord,
+        Self: Sized,
+    {
+        self.max_by(Ord::cmp)
+    }
+
+    /// Returns the minimum item.
+    ///
+    /// If several items are equally minimum, the first one is returned.
+    fn min(self) -> Option<Self::Item>
+    where
+        Self::Item: Ord,
+        Self: Sized,
+    {
+        self.min_by(Ord::cmp)
+    }
+
+    /// Returns the item which gives the maximum value from `f`.
+    ///
+    /// `f` is called once per item. If several items are equally maximum, the
+    /// last one is returned.
+    fn max_by_key<B, F>(self, mut f: F) -> Option<Self::Item>
+    where
+        B: Ord,
+        F: FnMut(&Self::Item) -> B,
+        Self: Sized,
+    {
+        let mut iter = self.iterate();
+        let mut max = iter.next()?;
+        let mut max_key = f(&max);
+        while let Some(item) = iter.next() {
+            let key = f(&item);
+            if key >= max_key {
+                max = item;
+                max_key = key;
+            }
+        }
+        Some(max)
+    }
+
+    /// Returns the item which gives the minimum value from `f`.
+    ///
+    /// `f` is called once per item. If several items are equally minimum, the
+    /// first one is returned.
+    fn min_by_key<B, F>(self, mut f: F) -> Option<Self::Item>
+    where
+        B: Ord,
+        F: FnMut(&Self::Item) -> B,
+        Self: Sized,
+    {
+        let mut iter = self.iterate();
+        let mut min = iter.next()?;
+        let mut min_key = f(&min);
+        while let Some(item) = iter.next() {
+            let key = f(&item);
+            if key < min_key {
+                min = item;
+                min_key = key;
+            }
+        }
+        Some(min)
+    }
+
+    /// Returns the maximum item with respect to a comparison function.
+    ///
+    /// If several items are equally maximum, the last one is returned.
+    fn max_by<F>(self, mut compare: F) -> Option<Self::Item>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        let mut iter = self.iterate();
+        let first = iter.next()?;
+        Some(iter.fold(first, |max, item| match compare(&max, &item) {
+            std::cmp::Ordering::Greater => max,
+            _ => item,
+        }))
+    }
+
+    /// Returns the minimum item with respect to a comparison function.
+    ///
+    /// If several items are equally minimum, the first one is returned.
+    fn min_by<F>(self, mut compare: F) -> Option<Self::Item>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        let mut iter = self.iterate();
+        let first = iter.next()?;
+        Some(iter.fold(first, |min, item| match compare(&min, &item) {
+            std::cmp::Ordering::Greater => item,
+            _ => min,
+        }))
+    }
+
+    /// Tests whether the items of this iterator are equal to those of `other`.
+    ///
+    /// Both iterators are consumed in lockstep; they're equal if they have
+    /// the same length and every pair of items is equal.
+    fn eq<U>(self, other: U) -> bool
+    where
+        U: Iterate,
+        Self::Item: PartialEq<U::Item>,
+        Self: Sized,
+    {
+        let mut a = self.iterate();
+        let mut b = other.iterate();
+        loop {
+            match (a.next(), b.next()) {
+                (None, None) => return true,
+                (Some(x), Some(y)) if x == y => {}
+                _ => return false,
+            }
+        }
+    }
+
+    /// Tests whether the items of this iterator are not equal to those of
+    /// `other`.
+    fn ne<U>(self, other: U) -> bool
+    where
+        U: Iterate,
+        Self::Item: PartialEq<U::Item>,
+        Self: Sized,
+    {
+        !self.eq(other)
+    }
+
+    /// Tests whether this iterator is lexicographically less than `other`.
+    fn lt<U>(self, other: U) -> bool
+    where
+        U: Iterate,
+        Self::Item: PartialOrd<U::Item>,
+        Self: Sized,
+    {
+        compare::partial_cmp(self.iterate(), other.iterate()) == Some(std::cmp::Ordering::Less)
+    }
+
+    /// Tests whether this iterator is lexicographically less than or equal to
+    /// `other`.
+    fn le<U>(self, other: U) -> bool
+    where
+        U: Iterate,
+        Self::Item: PartialOrd<U::Item>,
+        Self: Sized,
+    {
+        matches!(
+            compare::partial_cmp(self.iterate(), other.iterate()),
+            Some(std::cmp::Ordering::Less | std::cmp::Ordering::Equal)
+        )
+    }
+
+    /// Tests whether this iterator is lexicographically greater than `other`.
+    fn gt<U>(self, other: U) -> bool
+    where
+        U: Iterate,
+        Self::Item: PartialOrd<U::Item>,
+        Self: Sized,
+    {
+        compare::partial_cmp(self.iterate(), other.iterate()) == Some(std::cmp::Ordering::Greater)
+    }
+
+    /// Tests whether this iterator is lexicographically greater than or equal
+    /// to `other`.
+    fn ge<U>(self, other: U) -> bool
+    where
+        U: Iterate,
+        Self::Item: PartialOrd<U::Item>,
+        Self: Sized,
+    {
+        matches!(
+            compare::partial_cmp(self.iterate(), other.iterate()),
+            Some(std::cmp::Ordering::Greater | std::cmp::Ordering::Equal)
+        )
+    }
+
+    /// Tests whether the items are sorted in non-decreasing order.
+    ///
+    /// Iteration stops at the first pair of items which is out of order.
+    #[allow(clippy::wrong_self_convention)]
+    fn is_sorted(self) -> bool
+    where
+        Self::Item: PartialOrd,
+        Self: Sized,
+    {
+        self.is_sorted_by(PartialOrd::partial_cmp)
+    }
+
+    /// Tests whether the items are sorted with respect to a comparison
+    /// function.
+    ///
+    /// Each pair of adjacent items must compare as `Less` or `Equal`;
+    /// iteration stops at the first pair which doesn't.
+    #[allow(clippy::wrong_self_convention)]
+    fn is_sorted_by<F>(self, mut compare: F) -> bool
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> Option<std::cmp::Ordering>,
+        Self: Sized,
+    {
+        let mut iter = self.iterate();
+        let mut prev = match iter.next() {
+            Some(item) => item,
+            None => return true,
+        };
+        while let Some(item) = iter.next() {
+            match compare(&prev, &item) {
+                Some(std::cmp::Ordering::Less | std::cmp::Ordering::Equal) => prev = item,
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    /// Tests whether the items are sorted by the key extracted with `f`.
+    #[allow(clippy::wrong_self_convention)]
+    fn is_sorted_by_key<K, F>(self, mut f: F) -> bool
+    where
+        F: FnMut(&Self::Item) -> K,
+        K: PartialOrd,
+        Self: Sized,
+    {
+        self.map(move |item| f(&item)).is_sorted()
+    }
+
+    /// Folds every item into an accumulator with a fallible function,
+    /// stopping at the first error.
+    ///
+    /// Once `f` returns an error no further items are pulled from the
+    /// iterator, and the error is returned as-is.
+    fn try_fold<B, E, F>(self, init: B, mut f: F) -> Result<B, E>
+    where
+        F: FnMut(B, Self::Item) -> Result<B, E>,
+        Self: Sized,
+    {
+        let mut iter = self.iterate();
+        let mut acc = init;
+        while let Some(item) = iter.next() {
+            acc = f(acc, item)?;
+        }
+        Ok(acc)
+    }
+
+    /// Applies a fallible function to each item, stopping at the first error.
+    fn try_for_each<F, E>(self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(Self::Item) -> Result<(), E>,
+        Self: Sized,
+    {
+        self.try_fold((), |(), item| f(item))
+    }
+
+    /// Splits an iterator of pairs into two collections.
+    fn unzip<A, B, FromA, FromB>(self) -> (FromA, FromB)
+    where
+        FromA: Collect<A>,
+        FromB: Collect<B>,
+        Self: Iterate<Item = (A, B)> + Sized,
+    {
+        let mut iter = self.iterate();
+        let (lower, _) = iter.size_hint();
+        let mut left = Vec::with_capacity(lower);
+        let mut right = Vec::with_capacity(lower);
+        while let Some((a, b)) = iter.next() {
+            left.push(a);
+            right.push(b);
+        }
+        (
+            FromA::collect(buffer::Buffer::new(left)),
+            FromB::collect(buffer::Buffer::new(right)),
+        )
+    }
+
+    /// Splits the items into two collections.
+    ///
+    /// Items for which `predicate` returns `true` go into the first
+    /// collection, all others into the second. Both keep the items in their
+    /// original order.
+    fn partition<B, P>(self, mut predicate: P) -> (B, B)
+    where
+        B: Default + ExtendCollect<Self::Item>,
+        P: FnMut(&Self::Item) -> bool,
+        Self: Sized,
+    {
+        let mut matched = B::default();
+        let mut unmatched = B::default();
+        self.for_each(|item| {
+            if predicate(&item) {
+                matched.extend_collect(Some(item));
+            } else {
+                unmatched.extend_collect(Some(item));
+            }
+        });
+        (matched, unmatched)
+    }
+
+    /// Sums the items.
+    ///
+    /// An empty iterator returns zero. Summing `Option` or `Result` items
+    /// stops at the first `None` or `Err`, which is returned instead.
+    fn sum<S>(self) -> S
+    where
+        S: Sum<Self::Item>,
+        Self: Sized,
+    {
+        Sum::sum(self)
+    }
+
+    /// Multiplies the items.
+    ///
+    /// An empty iterator returns one. Multiplying `Option` or `Result` items
+    /// stops at the first `None` or `Err`, which is returned instead.
+    fn product<P>(self) -> P
+    where
+        P: Product<Self::Item>,
+        Self: Sized,
+    {
+        Product::product(self)
+    }
+
     /// Transforms this iterator into a collection.
     fn collect<B: Collect<Self::Item>>(self) -> B
     where
@@ -114,6 +1135,78 @@ pub trait Iterate {
     {
         Collect::collect(self)
     }
+
+    /// Transforms an iterator of `Result` items into a collection, stopping at
+    /// the first `Err`.
+    ///
+    /// No items are pulled from the source after the first `Err`, which is
+    /// returned instead of the collection.
+    fn try_collect<B, T, E>(self) -> Result<B, E>
+    where
+        B: Collect<T>,
+        Self: Iterate<Item = Result<T, E>> + Sized,
+    {
+        self.collect()
+    }
+
+    /// Extends an existing collection with the items of this iterator.
+    fn collect_into<E: ExtendCollect<Self::Item>>(self, collection: &mut E) -> &mut E
+    where
+        Self: Sized,
+    {
+        collection.extend_collect(self);
+        collection
+    }
+
+    /// Counts how often each distinct item occurs.
+    fn counts(self) -> std::collections::HashMap<Self::Item, usize>
+    where
+        Self::Item: Eq + std::hash::Hash,
+        Self: Sized,
+    {
+        let mut counts = std::collections::HashMap::new();
+        self.for_each(|item| *counts.entry(item).or_insert(0) += 1);
+        counts
+    }
+
+    /// Returns a value which displays every item, placing `sep` between
+    /// adjacent items.
+    ///
+    /// Unlike [`Iterate::join`] this doesn't allocate: the items are written
+    /// straight to the formatter. Formatting flags such as width and precision
+    /// are applied to each item.
+    ///
+    /// # Panics
+    ///
+    /// The returned value panics if it's formatted more than once.
+    fn format(self, sep: &str) -> format::Format<'_, Self>
+    where
+        Self::Item: std::fmt::Display,
+        Self: Sized,
+    {
+        format::Format::new(self, sep)
+    }
+
+    /// Formats every item with `Display` and concatenates them, placing `sep`
+    /// between adjacent items.
+    fn join(self, sep: &str) -> String
+    where
+        Self::Item: std::fmt::Display,
+        Self: Sized,
+    {
+        use std::fmt::Write;
+
+        let mut iter = self.iterate();
+        let mut out = String::new();
+        if let Some(first) = iter.next() {
+            write!(out, "{}", first).expect("writing to a String can't fail");
+        }
+        while let Some(item) = iter.next() {
+            out.push_str(sep);
+            write!(out, "{}", item).expect("writing to a String can't fail");
+        }
+        out
+    }
 }
 
 impl<T> Iterate for T
@@ -129,8 +1222,86 @@ where
     }
 }
 
+/// An [`Iterate`] whose [`Iterator`] can be traversed from both ends.
+pub trait DoubleEndedIterate: Iterate<Iterator: DoubleEndedIterator> {
+    /// Reverses the direction of iteration.
+    fn rev(self) -> rev::IntoRev<Self>
+    where
+        Self: Sized,
+    {
+        rev::IntoRev::new(self)
+    }
+
+    /// Searches for an item from the back, returning its index from the front.
+    ///
+    /// Iteration stops as soon as a matching item is found.
+    fn rposition<P>(self, mut predicate: P) -> Option<usize>
+    where
+        P: FnMut(&Self::Item) -> bool,
+        Self: ExactSizeIterate + Sized,
+    {
+        let mut iter = self.iterate();
+        let mut index = iter.len();
+        while let Some(item) = iter.next_back() {
+            index -= 1;
+            if predicate(&item) {
+                return Some(index);
+            }
+        }
+        None
+    }
+}
+
+impl<T> DoubleEndedIterate for T
+where
+    T: Iterate,
+    T::Iterator: DoubleEndedIterator,
+{
+}
+
+/// An [`Iterate`] whose [`Iterator`] knows its exact length.
+pub trait ExactSizeIterate: Iterate<Iterator: ExactSizeIterator> {}
+
+impl<T> ExactSizeIterate for T
+where
+    T: Iterate,
+    T::Iterator: ExactSizeIterator,
+{
+}
+
+/// An [`Iterate`] whose [`Iterator`] stays exhausted after yielding `None`.
+pub trait FusedIterate: Iterate<Iterator: FusedIterator> {}
+
+impl<T> FusedIterate for T
+where
+    T: Iterate,
+    T::Iterator: FusedIterator,
+{
+}
+
 /// Iterate over items and collect them into a value.
 pub trait Collect<A>: Sized {
     /// Creates a value from an `Iterate`.
     fn collect<T: Iterate<Item = A>>(iter: T) -> Self;
 }
+
+/// Sum the items of an iterator.
+pub trait Sum<A = Self>: Sized {
+    /// Sums the items of an `Iterate`, starting from zero.
+    fn sum<T: Iterate<Item = A>>(iter: T) -> Self;
+}
+
+/// Multiply the items of an iterator.
+pub trait Product<A = Self>: Sized {
+    /// Multiplies the items of an `Iterate`, starting from one.
+    fn product<T: Iterate<Item = A>>(iter: T) -> Self;
+}
+
+/// Extend a collection with the items of an iterator.
+///
+/// This is the counterpart to `std`'s `Extend`, and the trait behind
+/// [`Iterate::collect_into`].
+pub trait ExtendCollect<A> {
+    /// Extends a collection with the items of an `Iterate`.
+    fn extend_collect<T: Iterate<Item = A>>(&mut self, iter: T);
+}
@@ -1,6 +1,6 @@
 //! Helper types for the `map` operation
 
-use super::{Iterate, Iterator};
+use super::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterate, Iterator};
 
 /// An iterator which maps items from one type to another
 #[derive(Debug)]
@@ -16,7 +16,7 @@ impl<I, F> Map<I, F> {
 }
 
 /// A type that can be converted into a map iterator.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct IntoMap<I, F> {
     iter: I,
     f: F,
@@ -38,8 +38,35 @@ where
     fn next(&mut self) -> Option<B> {
         self.iter.next().map(&mut self.f)
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
+impl<B, I: DoubleEndedIterator, F> DoubleEndedIterator for Map<I, F>
+where
+    F: FnMut(I::Item) -> B,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<B> {
+        self.iter.next_back().map(&mut self.f)
+    }
+}
+
+impl<B, I: ExactSizeIterator, F> ExactSizeIterator for Map<I, F>
+where
+    F: FnMut(I::Item) -> B,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<B, I: FusedIterator, F> FusedIterator for Map<I, F> where F: FnMut(I::Item) -> B {}
+
 impl<B, I: Iterate, F> Iterate for IntoMap<I, F>
 where
     F: FnMut(I::Item) -> B,
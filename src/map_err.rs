@@ -0,0 +1,90 @@
+//! Helper types for the `map_err` operation
+
+use super::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterate, Iterator};
+
+/// An iterator which maps the `Err` values of `Result` items
+#[derive(Debug)]
+pub struct MapErr<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> MapErr<I, F> {
+    fn new(iter: I, f: F) -> MapErr<I, F> {
+        MapErr { iter, f }
+    }
+}
+
+/// A type that can be converted into a map-err iterator.
+#[derive(Debug, Clone)]
+pub struct IntoMapErr<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> IntoMapErr<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { iter, f }
+    }
+}
+
+impl<T, E, U, I, F> Iterator for MapErr<I, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FnMut(E) -> U,
+{
+    type Item = Result<T, U>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<T, U>> {
+        self.iter.next().map(|item| item.map_err(&mut self.f))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, E, U, I, F> DoubleEndedIterator for MapErr<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<T, E>>,
+    F: FnMut(E) -> U,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Result<T, U>> {
+        self.iter.next_back().map(|item| item.map_err(&mut self.f))
+    }
+}
+
+impl<T, E, U, I, F> ExactSizeIterator for MapErr<I, F>
+where
+    I: ExactSizeIterator<Item = Result<T, E>>,
+    F: FnMut(E) -> U,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<T, E, U, I, F> FusedIterator for MapErr<I, F>
+where
+    I: FusedIterator<Item = Result<T, E>>,
+    F: FnMut(E) -> U,
+{
+}
+
+impl<T, E, U, I, F> Iterate for IntoMapErr<I, F>
+where
+    I: Iterate<Item = Result<T, E>>,
+    F: FnMut(E) -> U,
+{
+    type Item = Result<T, U>;
+
+    type Iterator = MapErr<I::Iterator, F>;
+
+    fn iterate(self) -> Self::Iterator {
+        MapErr::new(self.iter.iterate(), self.f)
+    }
+}
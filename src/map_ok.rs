@@ -0,0 +1,90 @@
+//! Helper types for the `map_ok` operation
+
+use super::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterate, Iterator};
+
+/// An iterator which maps the `Ok` values of `Result` items
+#[derive(Debug)]
+pub struct MapOk<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> MapOk<I, F> {
+    fn new(iter: I, f: F) -> MapOk<I, F> {
+        MapOk { iter, f }
+    }
+}
+
+/// A type that can be converted into a map-ok iterator.
+#[derive(Debug, Clone)]
+pub struct IntoMapOk<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> IntoMapOk<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { iter, f }
+    }
+}
+
+impl<T, E, U, I, F> Iterator for MapOk<I, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FnMut(T) -> U,
+{
+    type Item = Result<U, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<U, E>> {
+        self.iter.next().map(|item| item.map(&mut self.f))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, E, U, I, F> DoubleEndedIterator for MapOk<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<T, E>>,
+    F: FnMut(T) -> U,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Result<U, E>> {
+        self.iter.next_back().map(|item| item.map(&mut self.f))
+    }
+}
+
+impl<T, E, U, I, F> ExactSizeIterator for MapOk<I, F>
+where
+    I: ExactSizeIterator<Item = Result<T, E>>,
+    F: FnMut(T) -> U,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<T, E, U, I, F> FusedIterator for MapOk<I, F>
+where
+    I: FusedIterator<Item = Result<T, E>>,
+    F: FnMut(T) -> U,
+{
+}
+
+impl<T, E, U, I, F> Iterate for IntoMapOk<I, F>
+where
+    I: Iterate<Item = Result<T, E>>,
+    F: FnMut(T) -> U,
+{
+    type Item = Result<U, E>;
+
+    type Iterator = MapOk<I::Iterator, F>;
+
+    fn iterate(self) -> Self::Iterator {
+        MapOk::new(self.iter.iterate(), self.f)
+    }
+}
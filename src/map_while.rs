@@ -0,0 +1,77 @@
+//! Helper types for the `map_while` operation
+
+use super::{FusedIterator, Iterate, Iterator};
+
+/// An iterator which maps items until the mapping function returns `None`
+#[derive(Debug)]
+pub struct MapWhile<I, F> {
+    iter: I,
+    f: F,
+    done: bool,
+}
+
+impl<I, F> MapWhile<I, F> {
+    fn new(iter: I, f: F) -> MapWhile<I, F> {
+        MapWhile {
+            iter,
+            f,
+            done: false,
+        }
+    }
+}
+
+/// A type that can be converted into a map-while iterator.
+#[derive(Debug, Clone)]
+pub struct IntoMapWhile<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> IntoMapWhile<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { iter, f }
+    }
+}
+
+impl<B, I: Iterator, F> Iterator for MapWhile<I, F>
+where
+    F: FnMut(I::Item) -> Option<B>,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<B> {
+        if self.done {
+            return None;
+        }
+        let item = self.iter.next().and_then(&mut self.f);
+        if item.is_none() {
+            self.done = true;
+        }
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+impl<B, I: Iterator, F> FusedIterator for MapWhile<I, F> where F: FnMut(I::Item) -> Option<B> {}
+
+impl<B, I: Iterate, F> Iterate for IntoMapWhile<I, F>
+where
+    F: FnMut(I::Item) -> Option<B>,
+{
+    type Item = B;
+
+    type Iterator = MapWhile<I::Iterator, F>;
+
+    fn iterate(self) -> Self::Iterator {
+        MapWhile::new(self.iter.iterate(), self.f)
+    }
+}
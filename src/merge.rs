@@ -0,0 +1,106 @@
+//! Helper types for the `merge` operation
+
+use super::{FusedIterator, Iterate, Iterator};
+
+/// An iterator which merges two sorted iterators into one sorted iterator
+#[derive(Debug)]
+pub struct Merge<A: Iterator, B> {
+    a: A,
+    b: B,
+    a_head: Option<A::Item>,
+    b_head: Option<A::Item>,
+}
+
+impl<A: Iterator, B> Merge<A, B> {
+    fn new(a: A, b: B) -> Merge<A, B> {
+        Merge {
+            a,
+            b,
+            a_head: None,
+            b_head: None,
+        }
+    }
+}
+
+/// A type that can be converted into a merging iterator.
+#[derive(Debug, Clone)]
+pub struct IntoMerge<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> IntoMerge<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+/// Adds a peeked item to a size hint.
+fn add_head((lower, upper): (usize, Option<usize>), head: bool) -> (usize, Option<usize>) {
+    let head = head as usize;
+    (
+        lower.saturating_add(head),
+        upper.and_then(|upper| upper.checked_add(head)),
+    )
+}
+
+impl<A, B> Iterator for Merge<A, B>
+where
+    A: Iterator,
+    A::Item: Ord,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<A::Item> {
+        let a = self.a_head.take().or_else(|| self.a.next());
+        let b = self.b_head.take().or_else(|| self.b.next());
+        match (a, b) {
+            (Some(a), Some(b)) if a <= b => {
+                self.b_head = Some(b);
+                Some(a)
+            }
+            (Some(a), Some(b)) => {
+                self.a_head = Some(a);
+                Some(b)
+            }
+            (Some(a), None) => Some(a),
+            (None, b) => b,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = add_head(self.a.size_hint(), self.a_head.is_some());
+        let (b_lower, b_upper) = add_head(self.b.size_hint(), self.b_head.is_some());
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        (a_lower.saturating_add(b_lower), upper)
+    }
+}
+
+impl<A, B> FusedIterator for Merge<A, B>
+where
+    A: FusedIterator,
+    A::Item: Ord,
+    B: FusedIterator<Item = A::Item>,
+{
+}
+
+impl<A, B> Iterate for IntoMerge<A, B>
+where
+    A: Iterate,
+    A::Item: Ord,
+    B: Iterate<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    type Iterator = Merge<A::Iterator, B::Iterator>;
+
+    fn iterate(self) -> Self::Iterator {
+        Merge::new(self.a.iterate(), self.b.iterate())
+    }
+}
@@ -0,0 +1,96 @@
+//! Helper types for the `multipeek` operation
+
+use super::{ExactSizeIterator, FusedIterator, Iterate, Iterator};
+use std::collections::VecDeque;
+
+/// An iterator which can peek arbitrarily far ahead without consuming items
+#[derive(Debug)]
+pub struct MultiPeek<I: Iterator> {
+    iter: I,
+    buf: VecDeque<I::Item>,
+    index: usize,
+}
+
+impl<I: Iterator> MultiPeek<I> {
+    fn new(iter: I) -> MultiPeek<I> {
+        MultiPeek {
+            iter,
+            buf: VecDeque::new(),
+            index: 0,
+        }
+    }
+
+    /// Returns a reference to the next item past the peek cursor, and
+    /// advances the cursor.
+    ///
+    /// Calling `peek` repeatedly looks further ahead. The cursor is moved
+    /// back to the front by [`MultiPeek::reset_peek`] or by calling `next`.
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        if self.index == self.buf.len() {
+            let item = self.iter.next()?;
+            self.buf.push_back(item);
+        }
+        let item = &self.buf[self.index];
+        self.index += 1;
+        Some(item)
+    }
+
+    /// Moves the peek cursor back to the front of the iterator.
+    pub fn reset_peek(&mut self) {
+        self.index = 0;
+    }
+}
+
+/// A type that can be converted into a multipeek iterator.
+#[derive(Debug, Clone)]
+pub struct IntoMultiPeek<I> {
+    iter: I,
+}
+
+impl<I> IntoMultiPeek<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter }
+    }
+}
+
+impl<I: Iterator> Iterator for MultiPeek<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        self.index = 0;
+        match self.buf.pop_front() {
+            Some(item) => Some(item),
+            None => self.iter.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let len = self.buf.len();
+        (
+            lower.saturating_add(len),
+            upper.and_then(|upper| upper.checked_add(len)),
+        )
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for MultiPeek<I> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len() + self.buf.len()
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for MultiPeek<I> {}
+
+impl<I: Iterate> Iterate for IntoMultiPeek<I> {
+    type Item = I::Item;
+
+    type Iterator = MultiPeek<I::Iterator>;
+
+    fn iterate(self) -> Self::Iterator {
+        MultiPeek::new(self.iter.iterate())
+    }
+}
@@ -0,0 +1,80 @@
+//! Helper types for the `ok` operation
+
+use super::{DoubleEndedIterator, FusedIterator, Iterate, Iterator};
+
+/// An iterator which yields the `Ok` values of `Result` items, skipping every
+/// `Err`
+#[derive(Debug)]
+pub struct OkValues<I> {
+    iter: I,
+}
+
+impl<I> OkValues<I> {
+    fn new(iter: I) -> OkValues<I> {
+        OkValues { iter }
+    }
+}
+
+/// A type that can be converted into an ok-values iterator.
+#[derive(Debug, Clone)]
+pub struct IntoOkValues<I> {
+    iter: I,
+}
+
+impl<I> IntoOkValues<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter }
+    }
+}
+
+impl<T, E, I> Iterator for OkValues<I>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        while let Some(item) = self.iter.next() {
+            if let Ok(item) = item {
+                return Some(item);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<T, E, I> DoubleEndedIterator for OkValues<I>
+where
+    I: DoubleEndedIterator<Item = Result<T, E>>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        while let Some(item) = self.iter.next_back() {
+            if let Ok(item) = item {
+                return Some(item);
+            }
+        }
+        None
+    }
+}
+
+impl<T, E, I> FusedIterator for OkValues<I> where I: FusedIterator<Item = Result<T, E>> {}
+
+impl<T, E, I> Iterate for IntoOkValues<I>
+where
+    I: Iterate<Item = Result<T, E>>,
+{
+    type Item = T;
+
+    type Iterator = OkValues<I::Iterator>;
+
+    fn iterate(self) -> Self::Iterator {
+        OkValues::new(self.iter.iterate())
+    }
+}
@@ -0,0 +1,61 @@
+//! `Iterate` support for `Option`
+//!
+//! An `Option` yields its value, if any. Since `Option` is also `Ord`,
+//! importing [`Iterate`] makes `a.max(b)` and `a.min(b)` ambiguous and turns
+//! `a.eq(&b)` and its siblings into calls to `Iterate`'s by-value
+//! comparisons. Spell those as `Ord::max(a, b)` or `PartialEq::eq(&a, &b)`.
+
+use super::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterate, Iterator};
+
+/// An iterator which yields the value of an `Option`, if any
+#[derive(Debug, Clone)]
+pub struct OptionIter<T> {
+    item: Option<T>,
+}
+
+impl<T> Iterator for OptionIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.item.take()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for OptionIter<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.item.take()
+    }
+}
+
+impl<T> ExactSizeIterator for OptionIter<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.item.is_some() as usize
+    }
+}
+
+impl<T> FusedIterator for OptionIter<T> {}
+
+/// Iterates the value of an `Option`, if any.
+///
+/// With `Iterate` in scope, `Some(1).max(Some(2))` is ambiguous between
+/// [`Iterate::max`] and `Ord::max`, and `a.eq(&b)`, `ne`, `lt`, `le`, `gt`
+/// and `ge` resolve to `Iterate`'s by-value methods, which don't accept a
+/// reference. Use `Ord::max(a, b)` and `PartialEq::eq(&a, &b)` instead.
+impl<T> Iterate for Option<T> {
+    type Item = T;
+
+    type Iterator = OptionIter<T>;
+
+    fn iterate(self) -> Self::Iterator {
+        OptionIter { item: self }
+    }
+}
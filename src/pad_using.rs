@@ -0,0 +1,88 @@
+//! Helper types for the `pad_using` operation
+
+use super::{FusedIterator, Iterate, Iterator};
+
+/// An iterator which pads a source to a minimum length
+#[derive(Debug)]
+pub struct PadUsing<I, F> {
+    iter: I,
+    min: usize,
+    index: usize,
+    done: bool,
+    f: F,
+}
+
+impl<I, F> PadUsing<I, F> {
+    fn new(iter: I, min: usize, f: F) -> PadUsing<I, F> {
+        PadUsing {
+            iter,
+            min,
+            index: 0,
+            done: false,
+            f,
+        }
+    }
+}
+
+/// A type that can be converted into a padding iterator.
+#[derive(Debug, Clone)]
+pub struct IntoPadUsing<I, F> {
+    iter: I,
+    min: usize,
+    f: F,
+}
+
+impl<I, F> IntoPadUsing<I, F> {
+    pub(crate) fn new(iter: I, min: usize, f: F) -> Self {
+        Self { iter, min, f }
+    }
+}
+
+impl<I: Iterator, F> Iterator for PadUsing<I, F>
+where
+    F: FnMut(usize) -> I::Item,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        let next = if self.done { None } else { self.iter.next() };
+        let item = match next {
+            Some(item) => item,
+            None => {
+                self.done = true;
+                if self.index >= self.min {
+                    return None;
+                }
+                (self.f)(self.index)
+            }
+        };
+        self.index = self.index.saturating_add(1);
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let padding = self.min.saturating_sub(self.index);
+        if self.done {
+            return (padding, Some(padding));
+        }
+        let (lower, upper) = self.iter.size_hint();
+        (lower.max(padding), upper.map(|upper| upper.max(padding)))
+    }
+}
+
+impl<I: Iterator, F> FusedIterator for PadUsing<I, F> where F: FnMut(usize) -> I::Item {}
+
+impl<I: Iterate, F> Iterate for IntoPadUsing<I, F>
+where
+    F: FnMut(usize) -> I::Item,
+{
+    type Item = I::Item;
+
+    type Iterator = PadUsing<I::Iterator, F>;
+
+    fn iterate(self) -> Self::Iterator {
+        PadUsing::new(self.iter.iterate(), self.min, self.f)
+    }
+}
@@ -0,0 +1,73 @@
+//! Helper types for the `positions` operation
+
+use super::{FusedIterator, Iterate, Iterator};
+
+/// An iterator which yields the indices of items matching a predicate
+#[derive(Debug)]
+pub struct Positions<I, P> {
+    iter: I,
+    predicate: P,
+    index: usize,
+}
+
+impl<I, P> Positions<I, P> {
+    fn new(iter: I, predicate: P) -> Positions<I, P> {
+        Positions {
+            iter,
+            predicate,
+            index: 0,
+        }
+    }
+}
+
+/// A type that can be converted into a positions iterator.
+#[derive(Debug, Clone)]
+pub struct IntoPositions<I, P> {
+    iter: I,
+    predicate: P,
+}
+
+impl<I, P> IntoPositions<I, P> {
+    pub(crate) fn new(iter: I, predicate: P) -> Self {
+        Self { iter, predicate }
+    }
+}
+
+impl<I: Iterator, P> Iterator for Positions<I, P>
+where
+    P: FnMut(I::Item) -> bool,
+{
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        while let Some(item) = self.iter.next() {
+            let index = self.index;
+            self.index += 1;
+            if (self.predicate)(item) {
+                return Some(index);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I: FusedIterator, P> FusedIterator for Positions<I, P> where P: FnMut(I::Item) -> bool {}
+
+impl<I: Iterate, P> Iterate for IntoPositions<I, P>
+where
+    P: FnMut(I::Item) -> bool,
+{
+    type Item = usize;
+
+    type Iterator = Positions<I::Iterator, P>;
+
+    fn iterate(self) -> Self::Iterator {
+        Positions::new(self.iter.iterate(), self.predicate)
+    }
+}
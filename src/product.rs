@@ -0,0 +1,35 @@
+//! `Product` implementations for `std` types
+
+use super::shunt::try_process;
+use super::{Iterate, Iterator, Product};
+
+macro_rules! product_impls {
+    ($one:expr, $($t:ty)*) => ($(
+        impl Product<$t> for $t {
+            fn product<I: Iterate<Item = $t>>(iter: I) -> Self {
+                let mut iter = iter.iterate();
+                let mut product = $one;
+                while let Some(item) = iter.next() {
+                    product *= item;
+                }
+                product
+            }
+        }
+    )*);
+}
+
+product_impls! { 1, i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+product_impls! { 1.0, f32 f64 }
+
+impl<T, U: Product<T>, E> Product<Result<T, E>> for Result<U, E> {
+    fn product<I: Iterate<Item = Result<T, E>>>(iter: I) -> Self {
+        try_process(iter.iterate(), |shunt| U::product(shunt))
+    }
+}
+
+impl<T, U: Product<T>> Product<Option<T>> for Option<U> {
+    fn product<I: Iterate<Item = Option<T>>>(iter: I) -> Self {
+        let iter = iter.map(|item| item.ok_or(())).iterate();
+        try_process(iter, |shunt| U::product(shunt)).ok()
+    }
+}
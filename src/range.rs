@@ -0,0 +1,124 @@
+//! `Iterate` support for ranges
+
+use super::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterate, Iterator};
+use std::iter;
+use std::ops::{Range, RangeInclusive};
+
+/// An iterator which yields the values of a `Range`
+#[derive(Debug, Clone)]
+pub struct RangeIter<T> {
+    range: Range<T>,
+}
+
+impl<T> Iterator for RangeIter<T>
+where
+    Range<T>: iter::Iterator<Item = T>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        iter::Iterator::next(&mut self.range)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        iter::Iterator::size_hint(&self.range)
+    }
+}
+
+impl<T> DoubleEndedIterator for RangeIter<T>
+where
+    Range<T>: iter::DoubleEndedIterator<Item = T>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        iter::DoubleEndedIterator::next_back(&mut self.range)
+    }
+}
+
+impl<T> ExactSizeIterator for RangeIter<T>
+where
+    Range<T>: iter::ExactSizeIterator<Item = T>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        iter::ExactSizeIterator::len(&self.range)
+    }
+}
+
+impl<T> FusedIterator for RangeIter<T> where Range<T>: iter::FusedIterator<Item = T> {}
+
+impl<T> Iterate for Range<T>
+where
+    Range<T>: iter::Iterator<Item = T>,
+{
+    type Item = T;
+
+    type Iterator = RangeIter<T>;
+
+    fn iterate(self) -> Self::Iterator {
+        RangeIter { range: self }
+    }
+}
+
+/// An iterator which yields the values of a `RangeInclusive`
+#[derive(Debug, Clone)]
+pub struct RangeInclusiveIter<T> {
+    range: RangeInclusive<T>,
+}
+
+impl<T> Iterator for RangeInclusiveIter<T>
+where
+    RangeInclusive<T>: iter::Iterator<Item = T>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        iter::Iterator::next(&mut self.range)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        iter::Iterator::size_hint(&self.range)
+    }
+}
+
+impl<T> DoubleEndedIterator for RangeInclusiveIter<T>
+where
+    RangeInclusive<T>: iter::DoubleEndedIterator<Item = T>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        iter::DoubleEndedIterator::next_back(&mut self.range)
+    }
+}
+
+impl<T> ExactSizeIterator for RangeInclusiveIter<T>
+where
+    RangeInclusive<T>: iter::ExactSizeIterator<Item = T>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        iter::ExactSizeIterator::len(&self.range)
+    }
+}
+
+impl<T> FusedIterator for RangeInclusiveIter<T> where
+    RangeInclusive<T>: iter::FusedIterator<Item = T>
+{
+}
+
+impl<T> Iterate for RangeInclusive<T>
+where
+    RangeInclusive<T>: iter::Iterator<Item = T>,
+{
+    type Item = T;
+
+    type Iterator = RangeInclusiveIter<T>;
+
+    fn iterate(self) -> Self::Iterator {
+        RangeInclusiveIter { range: self }
+    }
+}
@@ -0,0 +1,50 @@
+//! `Iterate` support for `Result`
+
+use super::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterate, Iterator};
+
+/// An iterator which yields the `Ok` value of a `Result`, if any
+#[derive(Debug, Clone)]
+pub struct ResultIter<T> {
+    item: Option<T>,
+}
+
+impl<T> Iterator for ResultIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.item.take()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for ResultIter<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.item.take()
+    }
+}
+
+impl<T> ExactSizeIterator for ResultIter<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.item.is_some() as usize
+    }
+}
+
+impl<T> FusedIterator for ResultIter<T> {}
+
+impl<T, E> Iterate for Result<T, E> {
+    type Item = T;
+
+    type Iterator = ResultIter<T>;
+
+    fn iterate(self) -> Self::Iterator {
+        ResultIter { item: self.ok() }
+    }
+}
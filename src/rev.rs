@@ -0,0 +1,69 @@
+//! Helper types for the `rev` operation
+
+use super::{
+    DoubleEndedIterate, DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterate, Iterator,
+};
+
+/// An iterator which yields items in reverse order
+#[derive(Debug)]
+pub struct Rev<I> {
+    iter: I,
+}
+
+impl<I> Rev<I> {
+    fn new(iter: I) -> Rev<I> {
+        Rev { iter }
+    }
+}
+
+/// A type that can be converted into a reversed iterator.
+#[derive(Debug, Clone)]
+pub struct IntoRev<I> {
+    iter: I,
+}
+
+impl<I> IntoRev<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter }
+    }
+}
+
+impl<I: DoubleEndedIterator> Iterator for Rev<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        self.iter.next_back()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for Rev<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Item> {
+        self.iter.next()
+    }
+}
+
+impl<I: DoubleEndedIterator + ExactSizeIterator> ExactSizeIterator for Rev<I> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I: DoubleEndedIterator + FusedIterator> FusedIterator for Rev<I> {}
+
+impl<I: DoubleEndedIterate> Iterate for IntoRev<I> {
+    type Item = I::Item;
+
+    type Iterator = Rev<I::Iterator>;
+
+    fn iterate(self) -> Self::Iterator {
+        Rev::new(self.iter.iterate())
+    }
+}
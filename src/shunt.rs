@@ -0,0 +1,59 @@
+//! Helpers for operations which stop at the first error
+
+use super::Iterator;
+
+/// An iterator which yields the `Ok` values of an iterator of `Result`s,
+/// stopping at the first `Err` and storing it.
+#[derive(Debug)]
+pub(crate) struct Shunt<'a, I, E> {
+    iter: I,
+    error: &'a mut Option<E>,
+}
+
+impl<I, T, E> Iterator for Shunt<'_, I, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.error.is_some() {
+            return None;
+        }
+        match self.iter.next()? {
+            Ok(item) => Some(item),
+            Err(err) => {
+                *self.error = Some(err);
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.error.is_some() {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+/// Run `f` over the `Ok` values of `iter`, returning the first `Err` instead
+/// if there was one.
+pub(crate) fn try_process<I, T, E, U, F>(iter: I, f: F) -> Result<U, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FnOnce(Shunt<'_, I, E>) -> U,
+{
+    let mut error = None;
+    let value = f(Shunt {
+        iter,
+        error: &mut error,
+    });
+    match error {
+        Some(err) => Err(err),
+        None => Ok(value),
+    }
+}
@@ -0,0 +1,73 @@
+//! Helper types for the `skip_last` operation
+
+use super::{FusedIterator, Iterate, Iterator};
+use std::collections::VecDeque;
+
+/// An iterator which skips the last `n` items of a source
+#[derive(Debug)]
+pub struct SkipLast<I: Iterator> {
+    iter: I,
+    buf: VecDeque<I::Item>,
+    n: usize,
+}
+
+impl<I: Iterator> SkipLast<I> {
+    fn new(iter: I, n: usize) -> SkipLast<I> {
+        SkipLast {
+            iter,
+            buf: VecDeque::new(),
+            n,
+        }
+    }
+}
+
+/// A type that can be converted into a skip-last iterator.
+#[derive(Debug, Clone)]
+pub struct IntoSkipLast<I> {
+    iter: I,
+    n: usize,
+}
+
+impl<I> IntoSkipLast<I> {
+    pub(crate) fn new(iter: I, n: usize) -> Self {
+        Self { iter, n }
+    }
+}
+
+impl<I: Iterator> Iterator for SkipLast<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        while self.buf.len() < self.n {
+            let item = self.iter.next()?;
+            self.buf.push_back(item);
+        }
+        let item = self.iter.next()?;
+        self.buf.push_back(item);
+        self.buf.pop_front()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let len = self.buf.len();
+        let lower = lower.saturating_add(len).saturating_sub(self.n);
+        let upper = upper
+            .and_then(|upper| upper.checked_add(len))
+            .map(|upper| upper.saturating_sub(self.n));
+        (lower, upper)
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for SkipLast<I> {}
+
+impl<I: Iterate> Iterate for IntoSkipLast<I> {
+    type Item = I::Item;
+
+    type Iterator = SkipLast<I::Iterator>;
+
+    fn iterate(self) -> Self::Iterator {
+        SkipLast::new(self.iter.iterate(), self.n)
+    }
+}
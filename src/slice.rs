@@ -0,0 +1,98 @@
+//! `Iterate` support for slices
+
+use super::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterate, Iterator};
+use std::slice;
+
+/// An iterator which yields shared references to the items of a slice
+#[derive(Debug, Clone)]
+pub struct SliceIter<'a, T> {
+    iter: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iterator for SliceIter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for SliceIter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for SliceIter<'_, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<T> FusedIterator for SliceIter<'_, T> {}
+
+impl<'a, T> Iterate for &'a [T] {
+    type Item = &'a T;
+
+    type Iterator = SliceIter<'a, T>;
+
+    fn iterate(self) -> Self::Iterator {
+        SliceIter { iter: self.iter() }
+    }
+}
+
+/// An iterator which yields mutable references to the items of a slice
+#[derive(Debug)]
+pub struct SliceIterMut<'a, T> {
+    iter: slice::IterMut<'a, T>,
+}
+
+impl<'a, T> Iterator for SliceIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for SliceIterMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for SliceIterMut<'_, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<T> FusedIterator for SliceIterMut<'_, T> {}
+
+impl<'a, T> Iterate for &'a mut [T] {
+    type Item = &'a mut T;
+
+    type Iterator = SliceIterMut<'a, T>;
+
+    fn iterate(self) -> Self::Iterator {
+        SliceIterMut {
+            iter: self.iter_mut(),
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! Helper types for the `sorted`, `sorted_by` and `sorted_by_key` operations
+
+use super::buffer::buffer;
+use super::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterate, Iterator};
+use std::cmp::Ordering;
+use std::vec;
+
+/// An iterator which yields the items of a source in sorted order
+#[derive(Debug)]
+pub struct Sorted<T> {
+    iter: vec::IntoIter<T>,
+}
+
+impl<T> Sorted<T> {
+    fn new(items: Vec<T>) -> Sorted<T> {
+        Sorted {
+            iter: items.into_iter(),
+        }
+    }
+}
+
+/// A type that can be converted into a sorted iterator.
+#[derive(Debug, Clone)]
+pub struct IntoSorted<I> {
+    iter: I,
+}
+
+impl<I> IntoSorted<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter }
+    }
+}
+
+/// A type that can be converted into an iterator sorted with a comparator.
+#[derive(Debug, Clone)]
+pub struct IntoSortedBy<I, F> {
+    iter: I,
+    compare: F,
+}
+
+impl<I, F> IntoSortedBy<I, F> {
+    pub(crate) fn new(iter: I, compare: F) -> Self {
+        Self { iter, compare }
+    }
+}
+
+/// A type that can be converted into an iterator sorted by a key.
+#[derive(Debug, Clone)]
+pub struct IntoSortedByKey<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> IntoSortedByKey<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { iter, f }
+    }
+}
+
+impl<T> Iterator for Sorted<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Sorted<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Sorted<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<T> FusedIterator for Sorted<T> {}
+
+impl<I: Iterate> Iterate for IntoSorted<I>
+where
+    I::Item: Ord,
+{
+    type Item = I::Item;
+
+    type Iterator = Sorted<I::Item>;
+
+    fn iterate(self) -> Self::Iterator {
+        let mut items = buffer(self.iter.iterate());
+        items.sort();
+        Sorted::new(items)
+    }
+}
+
+impl<I: Iterate, F> Iterate for IntoSortedBy<I, F>
+where
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    type Item = I::Item;
+
+    type Iterator = Sorted<I::Item>;
+
+    fn iterate(self) -> Self::Iterator {
+        let mut items = buffer(self.iter.iterate());
+        items.sort_by(self.compare);
+        Sorted::new(items)
+    }
+}
+
+impl<K: Ord, I: Iterate, F> Iterate for IntoSortedByKey<I, F>
+where
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = I::Item;
+
+    type Iterator = Sorted<I::Item>;
+
+    fn iterate(self) -> Self::Iterator {
+        let mut f = self.f;
+        let mut keyed: Vec<(K, I::Item)> = buffer(self.iter.iterate())
+            .into_iter()
+            .map(|item| (f(&item), item))
+            .collect();
+        keyed.sort_by(|a, b| a.0.cmp(&b.0));
+        Sorted::new(keyed.into_iter().map(|(_, item)| item).collect())
+    }
+}
@@ -0,0 +1,240 @@
+//! Functions which create new [`Iterate`](super::Iterate) values
+
+use super::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterator};
+use std::marker::PhantomData;
+
+/// Creates an iterator which yields an item exactly once.
+pub fn once<T>(value: T) -> Once<T> {
+    Once { item: Some(value) }
+}
+
+/// Creates an iterator which yields nothing.
+pub fn empty<T>() -> Empty<T> {
+    Empty {
+        _marker: PhantomData,
+    }
+}
+
+/// Creates an iterator which endlessly repeats a single item.
+pub fn repeat<T: Clone>(value: T) -> Repeat<T> {
+    Repeat { item: value }
+}
+
+/// Creates an iterator which endlessly calls a closure to produce each item.
+///
+/// Unlike [`repeat`], the item type doesn't need to implement `Clone`.
+pub fn repeat_with<T, F>(f: F) -> RepeatWith<F>
+where
+    F: FnMut() -> T,
+{
+    RepeatWith { f }
+}
+
+/// Creates an iterator which calls a closure to produce each item.
+///
+/// Iteration ends when the closure returns `None`.
+pub fn from_fn<T, F>(f: F) -> FromFn<F>
+where
+    F: FnMut() -> Option<T>,
+{
+    FromFn { f }
+}
+
+/// Creates an iterator where each item is computed from the one before it.
+///
+/// Iteration starts with `first` and ends when `succ` returns `None`.
+pub fn successors<T, F>(first: Option<T>, succ: F) -> Successors<T, F>
+where
+    F: FnMut(&T) -> Option<T>,
+{
+    Successors { next: first, succ }
+}
+
+/// An iterator which yields an item exactly once
+///
+/// This `struct` is created by the [`once`] function.
+#[derive(Debug, Clone)]
+pub struct Once<T> {
+    item: Option<T>,
+}
+
+impl<T> Iterator for Once<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.item.take()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Once<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.item.take()
+    }
+}
+
+impl<T> ExactSizeIterator for Once<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.item.is_some() as usize
+    }
+}
+
+impl<T> FusedIterator for Once<T> {}
+
+/// An iterator which yields nothing
+///
+/// This `struct` is created by the [`empty`] function.
+#[derive(Debug, Clone)]
+pub struct Empty<T> {
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Iterator for Empty<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
+}
+
+impl<T> DoubleEndedIterator for Empty<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        None
+    }
+}
+
+impl<T> ExactSizeIterator for Empty<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        0
+    }
+}
+
+impl<T> FusedIterator for Empty<T> {}
+
+/// An iterator which endlessly repeats a single item
+///
+/// This `struct` is created by the [`repeat`] function.
+#[derive(Debug, Clone)]
+pub struct Repeat<T> {
+    item: T,
+}
+
+impl<T: Clone> Iterator for Repeat<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        Some(self.item.clone())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for Repeat<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        Some(self.item.clone())
+    }
+}
+
+impl<T: Clone> FusedIterator for Repeat<T> {}
+
+/// An iterator which endlessly calls a closure to produce each item
+///
+/// This `struct` is created by the [`repeat_with`] function.
+#[derive(Debug, Clone)]
+pub struct RepeatWith<F> {
+    f: F,
+}
+
+impl<T, F> Iterator for RepeatWith<F>
+where
+    F: FnMut() -> T,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        Some((self.f)())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<T, F> FusedIterator for RepeatWith<F> where F: FnMut() -> T {}
+
+/// An iterator which calls a closure to produce each item
+///
+/// This `struct` is created by the [`from_fn`] function.
+#[derive(Debug, Clone)]
+pub struct FromFn<F> {
+    f: F,
+}
+
+impl<T, F> Iterator for FromFn<F>
+where
+    F: FnMut() -> Option<T>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        (self.f)()
+    }
+}
+
+/// An iterator where each item is computed from the one before it
+///
+/// This `struct` is created by the [`successors`] function.
+#[derive(Debug, Clone)]
+pub struct Successors<T, F> {
+    next: Option<T>,
+    succ: F,
+}
+
+impl<T, F> Iterator for Successors<T, F>
+where
+    F: FnMut(&T) -> Option<T>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        let item = self.next.take()?;
+        self.next = (self.succ)(&item);
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.next.is_some() {
+            (1, None)
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+impl<T, F> FusedIterator for Successors<T, F> where F: FnMut(&T) -> Option<T> {}
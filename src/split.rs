@@ -0,0 +1,84 @@
+//! Helper types for the `split` operation
+
+use super::{FusedIterator, Iterate, Iterator};
+
+/// An iterator which yields segments of items delimited by separators
+#[derive(Debug)]
+pub struct Split<I, P> {
+    iter: I,
+    predicate: P,
+    done: bool,
+}
+
+impl<I, P> Split<I, P> {
+    fn new(iter: I, predicate: P) -> Split<I, P> {
+        Split {
+            iter,
+            predicate,
+            done: false,
+        }
+    }
+}
+
+/// A type that can be converted into a split iterator.
+#[derive(Debug, Clone)]
+pub struct IntoSplit<I, P> {
+    iter: I,
+    predicate: P,
+}
+
+impl<I, P> IntoSplit<I, P> {
+    pub(crate) fn new(iter: I, predicate: P) -> Self {
+        Self { iter, predicate }
+    }
+}
+
+impl<I: Iterator, P> Iterator for Split<I, P>
+where
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = Vec<I::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        if self.done {
+            return None;
+        }
+        let mut segment = vec![];
+        loop {
+            match self.iter.next() {
+                Some(item) if (self.predicate)(&item) => return Some(segment),
+                Some(item) => segment.push(item),
+                None => {
+                    self.done = true;
+                    return Some(segment);
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.iter.size_hint();
+            (1, upper.and_then(|upper| upper.checked_add(1)))
+        }
+    }
+}
+
+impl<I: Iterator, P> FusedIterator for Split<I, P> where P: FnMut(&I::Item) -> bool {}
+
+impl<I: Iterate, P> Iterate for IntoSplit<I, P>
+where
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = Vec<I::Item>;
+
+    type Iterator = Split<I::Iterator, P>;
+
+    fn iterate(self) -> Self::Iterator {
+        Split::new(self.iter.iterate(), self.predicate)
+    }
+}
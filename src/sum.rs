@@ -0,0 +1,35 @@
+//! `Sum` implementations for `std` types
+
+use super::shunt::try_process;
+use super::{Iterate, Iterator, Sum};
+
+macro_rules! sum_impls {
+    ($zero:expr, $($t:ty)*) => ($(
+        impl Sum<$t> for $t {
+            fn sum<I: Iterate<Item = $t>>(iter: I) -> Self {
+                let mut iter = iter.iterate();
+                let mut sum = $zero;
+                while let Some(item) = iter.next() {
+                    sum += item;
+                }
+                sum
+            }
+        }
+    )*);
+}
+
+sum_impls! { 0, i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+sum_impls! { 0.0, f32 f64 }
+
+impl<T, U: Sum<T>, E> Sum<Result<T, E>> for Result<U, E> {
+    fn sum<I: Iterate<Item = Result<T, E>>>(iter: I) -> Self {
+        try_process(iter.iterate(), |shunt| U::sum(shunt))
+    }
+}
+
+impl<T, U: Sum<T>> Sum<Option<T>> for Option<U> {
+    fn sum<I: Iterate<Item = Option<T>>>(iter: I) -> Self {
+        let iter = iter.map(|item| item.ok_or(())).iterate();
+        try_process(iter, |shunt| U::sum(shunt)).ok()
+    }
+}
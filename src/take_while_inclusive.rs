@@ -0,0 +1,79 @@
+//! Helper types for the `take_while_inclusive` operation
+
+use super::{FusedIterator, Iterate, Iterator};
+
+/// An iterator which yields items while a predicate holds, including the
+/// first item for which it doesn't
+#[derive(Debug)]
+pub struct TakeWhileInclusive<I, P> {
+    iter: I,
+    predicate: P,
+    done: bool,
+}
+
+impl<I, P> TakeWhileInclusive<I, P> {
+    fn new(iter: I, predicate: P) -> TakeWhileInclusive<I, P> {
+        TakeWhileInclusive {
+            iter,
+            predicate,
+            done: false,
+        }
+    }
+}
+
+/// A type that can be converted into a take-while-inclusive iterator.
+#[derive(Debug, Clone)]
+pub struct IntoTakeWhileInclusive<I, P> {
+    iter: I,
+    predicate: P,
+}
+
+impl<I, P> IntoTakeWhileInclusive<I, P> {
+    pub(crate) fn new(iter: I, predicate: P) -> Self {
+        Self { iter, predicate }
+    }
+}
+
+impl<I: Iterator, P> Iterator for TakeWhileInclusive<I, P>
+where
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        if self.done {
+            return None;
+        }
+        let item = self.iter.next()?;
+        if !(self.predicate)(&item) {
+            self.done = true;
+        }
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+impl<I: FusedIterator, P> FusedIterator for TakeWhileInclusive<I, P> where P: FnMut(&I::Item) -> bool
+{}
+
+impl<I: Iterate, P> Iterate for IntoTakeWhileInclusive<I, P>
+where
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    type Iterator = TakeWhileInclusive<I::Iterator, P>;
+
+    fn iterate(self) -> Self::Iterator {
+        TakeWhileInclusive::new(self.iter.iterate(), self.predicate)
+    }
+}
@@ -0,0 +1,29 @@
+//! Helper types for the `tee` operation
+
+use super::Iterate;
+
+/// One of two independent copies of an [`Iterate`] builder
+///
+/// `Tee` clones the builder rather than buffering items, so each copy runs
+/// the source from the start. Any side effects of the source will run once
+/// per copy that is iterated.
+#[derive(Debug, Clone)]
+pub struct Tee<I> {
+    iter: I,
+}
+
+impl<I> Tee<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter }
+    }
+}
+
+impl<I: Iterate> Iterate for Tee<I> {
+    type Item = I::Item;
+
+    type Iterator = I::Iterator;
+
+    fn iterate(self) -> Self::Iterator {
+        self.iter.iterate()
+    }
+}
@@ -0,0 +1,85 @@
+//! Helper types for the `update` operation
+
+use super::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterate, Iterator};
+
+/// An iterator which mutates each item before yielding it
+#[derive(Debug)]
+pub struct Update<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> Update<I, F> {
+    fn new(iter: I, f: F) -> Update<I, F> {
+        Update { iter, f }
+    }
+}
+
+/// A type that can be converted into an updating iterator.
+#[derive(Debug, Clone)]
+pub struct IntoUpdate<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> IntoUpdate<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { iter, f }
+    }
+}
+
+impl<I: Iterator, F> Iterator for Update<I, F>
+where
+    F: FnMut(&mut I::Item),
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        let mut item = self.iter.next()?;
+        (self.f)(&mut item);
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator, F> DoubleEndedIterator for Update<I, F>
+where
+    F: FnMut(&mut I::Item),
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Item> {
+        let mut item = self.iter.next_back()?;
+        (self.f)(&mut item);
+        Some(item)
+    }
+}
+
+impl<I: ExactSizeIterator, F> ExactSizeIterator for Update<I, F>
+where
+    F: FnMut(&mut I::Item),
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I: FusedIterator, F> FusedIterator for Update<I, F> where F: FnMut(&mut I::Item) {}
+
+impl<I: Iterate, F> Iterate for IntoUpdate<I, F>
+where
+    F: FnMut(&mut I::Item),
+{
+    type Item = I::Item;
+
+    type Iterator = Update<I::Iterator, F>;
+
+    fn iterate(self) -> Self::Iterator {
+        Update::new(self.iter.iterate(), self.f)
+    }
+}
@@ -0,0 +1,60 @@
+//! `Iterate` support for `Vec`
+
+use super::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterate, Iterator};
+use std::vec;
+
+/// An iterator which yields the items of a `Vec` by value
+#[derive(Debug, Clone)]
+pub struct VecIter<T> {
+    iter: vec::IntoIter<T>,
+}
+
+impl<T> Iterator for VecIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for VecIter<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for VecIter<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<T> FusedIterator for VecIter<T> {}
+
+/// Iterates a `Vec` by value.
+///
+/// With `Iterate` in scope, method calls on a `Vec` find `Iterate`'s by-value
+/// methods before the by-reference methods of `std`'s comparison traits:
+/// `a.eq(&b)` resolves to [`Iterate::eq`] and fails to compile, and likewise
+/// for `ne`, `lt`, `le`, `gt` and `ge`, while `a.max(b)` and `a.min(b)` are
+/// ambiguous with `Ord`. Call those through their traits instead, as in
+/// `PartialEq::eq(&a, &b)` or `Ord::max(a, b)`.
+impl<T> Iterate for Vec<T> {
+    type Item = T;
+
+    type Iterator = VecIter<T>;
+
+    fn iterate(self) -> Self::Iterator {
+        VecIter {
+            iter: self.into_iter(),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! Helper types for the `while_some` operation
+
+use super::{FusedIterator, Iterate, Iterator};
+
+/// An iterator which yields the values of `Option` items until the first
+/// `None`
+#[derive(Debug)]
+pub struct WhileSome<I> {
+    iter: I,
+    done: bool,
+}
+
+impl<I> WhileSome<I> {
+    fn new(iter: I) -> WhileSome<I> {
+        WhileSome { iter, done: false }
+    }
+}
+
+/// A type that can be converted into a while-some iterator.
+#[derive(Debug, Clone)]
+pub struct IntoWhileSome<I> {
+    iter: I,
+}
+
+impl<I> IntoWhileSome<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter }
+    }
+}
+
+impl<T, I> Iterator for WhileSome<I>
+where
+    I: Iterator<Item = Option<T>>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.done {
+            return None;
+        }
+        let item = self.iter.next().flatten();
+        if item.is_none() {
+            self.done = true;
+        }
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+impl<T, I> FusedIterator for WhileSome<I> where I: Iterator<Item = Option<T>> {}
+
+impl<T, I> Iterate for IntoWhileSome<I>
+where
+    I: Iterate<Item = Option<T>>,
+{
+    type Item = T;
+
+    type Iterator = WhileSome<I::Iterator>;
+
+    fn iterate(self) -> Self::Iterator {
+        WhileSome::new(self.iter.iterate())
+    }
+}
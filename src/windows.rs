@@ -0,0 +1,92 @@
+//! Helper types for the `windows` operation
+
+use super::{FusedIterator, Iterate, Iterator};
+use std::collections::VecDeque;
+
+/// An iterator which yields overlapping windows of items
+#[derive(Debug)]
+pub struct Windows<I: Iterator> {
+    iter: I,
+    buf: VecDeque<I::Item>,
+    size: usize,
+}
+
+impl<I: Iterator> Windows<I> {
+    fn new(iter: I, size: usize) -> Windows<I> {
+        Windows {
+            iter,
+            buf: VecDeque::with_capacity(size),
+            size,
+        }
+    }
+
+    /// Computes the number of windows left given `n` more source items.
+    fn windows_left(&self, n: usize) -> Option<usize> {
+        let buffered = if self.buf.len() == self.size {
+            self.size - 1
+        } else {
+            self.buf.len()
+        };
+        Some(
+            n.checked_add(buffered)?
+                .checked_add(1)?
+                .saturating_sub(self.size),
+        )
+    }
+}
+
+/// A type that can be converted into a windows iterator.
+#[derive(Debug, Clone)]
+pub struct IntoWindows<I> {
+    iter: I,
+    size: usize,
+}
+
+impl<I> IntoWindows<I> {
+    pub(crate) fn new(iter: I, size: usize) -> Self {
+        assert!(size != 0, "window size must be non-zero");
+        Self { iter, size }
+    }
+}
+
+impl<I: Iterator> Iterator for Windows<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        if self.buf.len() == self.size {
+            self.buf.pop_front();
+        }
+        while self.buf.len() < self.size {
+            let item = self.iter.next()?;
+            self.buf.push_back(item);
+        }
+        Some(self.buf.iter().cloned().collect())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let lower = self.windows_left(lower).unwrap_or(usize::MAX);
+        let upper = upper.and_then(|upper| self.windows_left(upper));
+        (lower, upper)
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for Windows<I> where I::Item: Clone {}
+
+impl<I: Iterate> Iterate for IntoWindows<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    type Iterator = Windows<I::Iterator>;
+
+    fn iterate(self) -> Self::Iterator {
+        Windows::new(self.iter.iterate(), self.size)
+    }
+}
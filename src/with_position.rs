@@ -0,0 +1,116 @@
+//! Helper types for the `with_position` operation
+
+use super::{ExactSizeIterator, FusedIterator, Iterate, Iterator};
+
+/// An item annotated with its position in an iterator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Position<T> {
+    /// The first of several items.
+    First(T),
+    /// Neither the first nor the last item.
+    Middle(T),
+    /// The last of several items.
+    Last(T),
+    /// The only item.
+    Only(T),
+}
+
+impl<T> Position<T> {
+    /// Returns the item, discarding its position.
+    pub fn into_inner(self) -> T {
+        match self {
+            Position::First(item)
+            | Position::Middle(item)
+            | Position::Last(item)
+            | Position::Only(item) => item,
+        }
+    }
+}
+
+/// An iterator which annotates items with their position
+#[derive(Debug)]
+pub struct WithPosition<I: Iterator> {
+    iter: I,
+    peeked: Option<I::Item>,
+    started: bool,
+}
+
+impl<I: Iterator> WithPosition<I> {
+    fn new(iter: I) -> WithPosition<I> {
+        WithPosition {
+            iter,
+            peeked: None,
+            started: false,
+        }
+    }
+}
+
+/// A type that can be converted into a with-position iterator.
+#[derive(Debug, Clone)]
+pub struct IntoWithPosition<I> {
+    iter: I,
+}
+
+impl<I> IntoWithPosition<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter }
+    }
+}
+
+impl<I: Iterator> Iterator for WithPosition<I> {
+    type Item = Position<I::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = !self.started;
+        self.started = true;
+        let item = match self.peeked.take() {
+            Some(item) => item,
+            None if first => self.iter.next()?,
+            None => return None,
+        };
+        self.peeked = self.iter.next();
+        let last = self.peeked.is_none();
+        Some(match (first, last) {
+            (true, true) => Position::Only(item),
+            (true, false) => Position::First(item),
+            (false, true) => Position::Last(item),
+            (false, false) => Position::Middle(item),
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.started && self.peeked.is_none() {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.iter.size_hint();
+        let peeked = self.peeked.is_some() as usize;
+        (
+            lower.saturating_add(peeked),
+            upper.and_then(|upper| upper.checked_add(peeked)),
+        )
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for WithPosition<I> {
+    #[inline]
+    fn len(&self) -> usize {
+        if self.started && self.peeked.is_none() {
+            return 0;
+        }
+        self.iter.len() + self.peeked.is_some() as usize
+    }
+}
+
+impl<I: Iterator> FusedIterator for WithPosition<I> {}
+
+impl<I: Iterate> Iterate for IntoWithPosition<I> {
+    type Item = Position<I::Item>;
+
+    type Iterator = WithPosition<I::Iterator>;
+
+    fn iterate(self) -> Self::Iterator {
+        WithPosition::new(self.iter.iterate())
+    }
+}
@@ -0,0 +1,84 @@
+//! Helper types for the `zip_longest` operation
+
+use super::{ExactSizeIterator, FusedIterator, Iterate, Iterator};
+
+/// A value which holds an item from either or both of two iterators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EitherOrBoth<A, B> {
+    /// Both iterators yielded an item.
+    Both(A, B),
+    /// Only the left iterator yielded an item.
+    Left(A),
+    /// Only the right iterator yielded an item.
+    Right(B),
+}
+
+/// An iterator which zips two iterators until both are exhausted
+#[derive(Debug)]
+pub struct ZipLongest<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> ZipLongest<A, B> {
+    fn new(a: A, b: B) -> ZipLongest<A, B> {
+        ZipLongest { a, b }
+    }
+}
+
+/// A type that can be converted into a longest-zipping iterator.
+#[derive(Debug, Clone)]
+pub struct IntoZipLongest<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> IntoZipLongest<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<A: Iterator, B: Iterator> Iterator for ZipLongest<A, B> {
+    type Item = EitherOrBoth<A::Item, B::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.a.next(), self.b.next()) {
+            (Some(a), Some(b)) => Some(EitherOrBoth::Both(a, b)),
+            (Some(a), None) => Some(EitherOrBoth::Left(a)),
+            (None, Some(b)) => Some(EitherOrBoth::Right(b)),
+            (None, None) => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            _ => None,
+        };
+        (a_lower.max(b_lower), upper)
+    }
+}
+
+impl<A: ExactSizeIterator, B: ExactSizeIterator> ExactSizeIterator for ZipLongest<A, B> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.a.len().max(self.b.len())
+    }
+}
+
+impl<A: FusedIterator, B: FusedIterator> FusedIterator for ZipLongest<A, B> {}
+
+impl<A: Iterate, B: Iterate> Iterate for IntoZipLongest<A, B> {
+    type Item = EitherOrBoth<A::Item, B::Item>;
+
+    type Iterator = ZipLongest<A::Iterator, B::Iterator>;
+
+    fn iterate(self) -> Self::Iterator {
+        ZipLongest::new(self.a.iterate(), self.b.iterate())
+    }
+}
@@ -0,0 +1,1713 @@
+use iterate_trait::{
+    Collect, DoubleEndedIterate, DoubleEndedIterator, ExactSizeIterate, ExactSizeIterator,
+    ExtendCollect, FusedIterate, Iterate, Iterator,
+};
+
+/// A test source backed by a `std` iterator.
+#[derive(Debug, Clone)]
+struct Source<I>(I);
+
+fn source<I: IntoIterator>(iter: I) -> Source<I::IntoIter> {
+    Source(iter.into_iter())
+}
+
+impl<I: std::iter::Iterator> Iterator for Source<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<I: std::iter::DoubleEndedIterator> DoubleEndedIterator for Source<I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<I: std::iter::ExactSizeIterator> ExactSizeIterator for Source<I> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+/// Drain an `Iterate` into a `Vec`.
+fn drain<I: Iterate>(iter: I) -> Vec<I::Item> {
+    let mut iter = iter.iterate();
+    let mut out = vec![];
+    while let Some(item) = iter.next() {
+        out.push(item);
+    }
+    out
+}
+
+/// A test collection which implements `Collect` and `ExtendCollect`.
+#[derive(Debug, PartialEq)]
+struct Bag<T>(Vec<T>);
+
+impl<T> Default for Bag<T> {
+    fn default() -> Self {
+        Bag(vec![])
+    }
+}
+
+impl<T> Collect<T> for Bag<T> {
+    fn collect<I: Iterate<Item = T>>(iter: I) -> Self {
+        Bag(drain(iter))
+    }
+}
+
+impl<T> ExtendCollect<T> for Bag<T> {
+    fn extend_collect<I: Iterate<Item = T>>(&mut self, iter: I) {
+        self.0.append(&mut drain(iter));
+    }
+}
+
+#[test]
+fn rev() {
+    assert_eq!(drain(source([1, 2, 3]).rev()), vec![3, 2, 1]);
+}
+
+#[test]
+fn rev_twice_is_identity() {
+    assert_eq!(drain(source([1, 2, 3]).rev().rev()), vec![1, 2, 3]);
+    assert_eq!(drain(source([1, 2, 3]).map(|x| x * 2).rev()), vec![6, 4, 2]);
+}
+
+#[test]
+fn try_for_each() {
+    let mut seen = vec![];
+    let res: Result<(), ()> = source([1, 2, 3]).try_for_each(|x| {
+        seen.push(x);
+        Ok(())
+    });
+    assert_eq!(res, Ok(()));
+    assert_eq!(seen, vec![1, 2, 3]);
+}
+
+#[test]
+fn try_for_each_stops_at_first_error() {
+    let mut seen = vec![];
+    let res = source([1, 2, 3, 4]).try_for_each(|x| {
+        seen.push(x);
+        if x == 2 {
+            Err(x)
+        } else {
+            Ok(())
+        }
+    });
+    assert_eq!(res, Err(2));
+    assert_eq!(seen, vec![1, 2]);
+}
+
+#[test]
+fn try_for_each_after_map() {
+    let mut pulled = 0;
+    let mut seen = vec![];
+    let res = source([1, 2, 3])
+        .map(|x| {
+            pulled += 1;
+            x * 10
+        })
+        .try_for_each(|x| {
+            if x == 20 {
+                return Err(x);
+            }
+            seen.push(x);
+            Ok(())
+        });
+    assert_eq!(res, Err(20));
+    assert_eq!(seen, vec![10]);
+    assert_eq!(pulled, 2);
+}
+
+#[test]
+fn multipeek() {
+    let mut iter = source([1, 2, 3, 4]).multipeek().iterate();
+    assert_eq!(iter.peek(), Some(&1));
+    assert_eq!(iter.peek(), Some(&2));
+    assert_eq!(iter.peek(), Some(&3));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.peek(), Some(&2));
+    assert_eq!(iter.peek(), Some(&3));
+    iter.reset_peek();
+    assert_eq!(iter.peek(), Some(&2));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.peek(), Some(&4));
+    assert_eq!(iter.peek(), None);
+    assert_eq!(iter.next(), Some(4));
+    assert_eq!(iter.peek(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn for_each() {
+    let mut out = vec![];
+    source([1, 2, 3]).map(|x| x * 10).for_each(|x| out.push(x));
+    assert_eq!(out, vec![10, 20, 30]);
+}
+
+fn exact_len<I: ExactSizeIterate>(iter: I) -> usize {
+    iter.iterate().len()
+}
+
+#[test]
+fn exact_size() {
+    assert_eq!(exact_len(source([1, 2, 3]).map(|x| x * 2)), 3);
+    assert_eq!(exact_len(source([1, 2, 3]).map(|x| x * 2).rev()), 3);
+
+    let mut iter = source([1, 2, 3]).multipeek().iterate();
+    iter.peek();
+    iter.peek();
+    assert_eq!(iter.len(), 3);
+    iter.next();
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+}
+
+#[test]
+fn take_while_inclusive() {
+    let iter = source([1, 2, 3, 10, 4, 5]).take_while_inclusive(|x| *x < 3);
+    assert_eq!(drain(iter), vec![1, 2, 3]);
+
+    let mut iter = source([1, 2, 3, 1])
+        .take_while_inclusive(|x| *x < 3)
+        .iterate();
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+
+    let iter = source([1, 2]).take_while_inclusive(|x| *x < 3);
+    assert_eq!(drain(iter), vec![1, 2]);
+}
+
+/// An iterator which alternates between yielding an item and yielding `None`.
+#[derive(Debug, Clone)]
+struct Flaky(u32);
+
+impl Iterator for Flaky {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        self.0 += 1;
+        if self.0.is_multiple_of(2) {
+            None
+        } else {
+            Some(self.0)
+        }
+    }
+}
+
+fn assert_fused<I: FusedIterate>(_: &I) {}
+
+#[test]
+fn fuse() {
+    let mut iter = Flaky(0).iterate();
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), Some(3));
+
+    let iter = Flaky(0).fuse();
+    assert_fused(&iter);
+    assert_fused(&Flaky(0).fuse().map(|x| x * 2));
+    let mut iter = iter.iterate();
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn count() {
+    assert_eq!(source(0..5).count(), 5);
+    assert_eq!(source((0..10).filter(|x| x % 3 == 0)).count(), 4);
+    assert_eq!(source(0..0).count(), 0);
+
+    let pulled = std::cell::Cell::new(0);
+    let iter = source((0..4).inspect(|_| pulled.set(pulled.get() + 1)));
+    assert_eq!(iter.count(), 4);
+    assert_eq!(pulled.get(), 4);
+}
+
+/// Counts the items of `iter`, asserting the count is within its size hint.
+fn hinted_count<I: Iterate>(iter: I) -> usize {
+    let iter = iter.iterate();
+    let (lower, upper) = iter.size_hint();
+    let count = iter.count();
+    assert!(
+        lower <= count,
+        "lower bound {} exceeds count {}",
+        lower,
+        count
+    );
+    if let Some(upper) = upper {
+        assert!(
+            count <= upper,
+            "count {} exceeds upper bound {}",
+            count,
+            upper
+        );
+    }
+    count
+}
+
+#[test]
+fn count_within_size_hint() {
+    assert_eq!(hinted_count(source(0..7).map(|x| x + 1)), 7);
+    assert_eq!(hinted_count(source(0..7).chunks(3)), 3);
+    assert_eq!(hinted_count(source(0..7).windows(3)), 5);
+    assert_eq!(hinted_count(source(0..7).array_chunks::<2>()), 3);
+    assert_eq!(hinted_count(source(0..7).skip_last(2)), 5);
+    assert_eq!(hinted_count(source(0..7).intersperse(0)), 13);
+    assert_eq!(hinted_count(source(0..3).pad_using(5, |i| i)), 5);
+    assert_eq!(hinted_count(source(0..3).interleave(source(0..5))), 8);
+    assert_eq!(hinted_count(source(0..3).merge(source(0..5))), 8);
+    assert_eq!(hinted_count(source(0..3).zip_longest(source(0..5))), 5);
+    assert_eq!(
+        hinted_count(source(0..3).cartesian_product(source(0..2))),
+        6
+    );
+    assert_eq!(hinted_count(source([1, 1, 2]).dedup()), 2);
+    assert_eq!(hinted_count(source(0..7).positions(|x| x % 2 == 0)), 4);
+    assert_eq!(hinted_count(source(0..7).sorted()), 7);
+}
+
+#[test]
+fn sorted() {
+    assert_eq!(drain(source([3, 1, 2]).sorted()), vec![1, 2, 3]);
+    assert_eq!(drain(source([3, 1, 2]).sorted().rev()), vec![3, 2, 1]);
+    assert_eq!(drain(source(Vec::<u8>::new()).sorted()), vec![]);
+}
+
+#[test]
+fn sorted_by_is_stable() {
+    let items = [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+    let iter = source(items).sorted_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(drain(iter), vec![(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+}
+
+#[test]
+fn sorted_is_lazy() {
+    let pulled = std::cell::Cell::new(0);
+    let iter = source(
+        vec![3, 1, 2]
+            .into_iter()
+            .inspect(|_| pulled.set(pulled.get() + 1)),
+    )
+    .sorted();
+    assert_eq!(pulled.get(), 0);
+    let mut iter = iter.iterate();
+    assert_eq!(pulled.get(), 3);
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next(), Some(1));
+}
+
+#[test]
+fn filter_map() {
+    let iter = source(vec!["1", "two", "3", "", "-4"]).filter_map(|s| s.parse::<i32>().ok());
+    assert_eq!(iter.iterate().size_hint(), (0, Some(5)));
+    let iter = source(vec!["1", "two", "3", "", "-4"]).filter_map(|s| s.parse::<i32>().ok());
+    assert_eq!(drain(iter), vec![1, 3, -4]);
+}
+
+#[test]
+fn last() {
+    assert_eq!(source([1, 2, 3]).last(), Some(3));
+    assert_eq!(source([1]).last(), Some(1));
+    assert_eq!(source(Vec::<u8>::new()).last(), None);
+}
+
+#[test]
+fn map_while() {
+    let iter = source([1, 2, 0, 3]).map_while(|x| if x == 0 { None } else { Some(x * 2) });
+    assert_eq!(drain(iter), vec![2, 4]);
+}
+
+#[test]
+fn sorted_by_key_caches_keys() {
+    #[derive(Debug, PartialEq)]
+    struct User {
+        name: String,
+        id: u32,
+    }
+    let user = |name: &str, id| User {
+        name: name.to_owned(),
+        id,
+    };
+
+    let calls = std::cell::Cell::new(0);
+    let users = vec![
+        user("carol", 1),
+        user("alice", 2),
+        user("bob", 3),
+        user("alice", 4),
+    ];
+    let iter = source(users).sorted_by_key(|u| {
+        calls.set(calls.get() + 1);
+        u.name.clone()
+    });
+    assert_eq!(
+        drain(iter),
+        vec![
+            user("alice", 2),
+            user("alice", 4),
+            user("bob", 3),
+            user("carol", 1)
+        ]
+    );
+    assert_eq!(calls.get(), 4);
+}
+
+#[test]
+fn nth() {
+    assert_eq!(source([1, 2, 3]).nth(0), Some(1));
+    assert_eq!(source([1, 2, 3]).nth(1), Some(2));
+    assert_eq!(source([1, 2, 3]).nth(3), None);
+    assert_eq!(source(Vec::<u8>::new()).nth(0), None);
+}
+
+#[test]
+fn last_and_nth_match_std() {
+    let data: [&[u8]; 4] = [&[], &[7], &[1, 2], &[5, 4, 3, 2, 1]];
+    for items in data.iter() {
+        assert_eq!(source(items.to_vec()).last(), items.iter().copied().last());
+        for n in 0..=items.len() + 1 {
+            assert_eq!(source(items.to_vec()).nth(n), items.iter().copied().nth(n));
+        }
+    }
+}
+
+#[test]
+fn coalesce() {
+    let iter = source("aaabccdd".chars()).map(|c| (c, 1)).coalesce(|a, b| {
+        if a.0 == b.0 {
+            Ok((a.0, a.1 + b.1))
+        } else {
+            Err((a, b))
+        }
+    });
+    assert_eq!(drain(iter), vec![('a', 3), ('b', 1), ('c', 2), ('d', 2)]);
+
+    let iter = source(Vec::<u8>::new()).coalesce(|a, b| Err((a, b)));
+    assert_eq!(drain(iter), vec![]);
+}
+
+#[test]
+fn intersperse() {
+    let iter = source(['a', 'b', 'c']).intersperse(',');
+    assert_eq!(drain(iter), vec!['a', ',', 'b', ',', 'c']);
+
+    let mut iter = source(['a', 'b', 'c']).intersperse(',').iterate();
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+    iter.next();
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    iter.next();
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+
+    let iter = source(Vec::<char>::new()).intersperse(',');
+    assert_eq!(iter.iterate().size_hint(), (0, Some(0)));
+    assert_eq!(drain(source(Vec::<char>::new()).intersperse(',')), vec![]);
+    assert_eq!(drain(source(['a']).intersperse(',')), vec!['a']);
+}
+
+#[test]
+fn intersperse_with() {
+    let mut n = 0;
+    let iter = source([10, 20, 30]).intersperse_with(|| {
+        n += 1;
+        n
+    });
+    assert_eq!(drain(iter), vec![10, 1, 20, 2, 30]);
+}
+
+#[test]
+fn find() {
+    assert_eq!(source([1, 2, 3, 4]).find(|x| x % 2 == 0), Some(2));
+    assert_eq!(source([1, 3, 5]).find(|x| x % 2 == 0), None);
+
+    let mut seen = vec![];
+    let found = source([1, 2, 3, 4]).find(|x| {
+        seen.push(*x);
+        *x == 2
+    });
+    assert_eq!(found, Some(2));
+    assert_eq!(seen, vec![1, 2]);
+}
+
+#[test]
+fn find_last_item() {
+    assert_eq!(vec![1, 3, 5, 6].find(|x| x % 2 == 0), Some(6));
+    assert_eq!(Vec::<u8>::new().find(|_| true), None);
+
+    let mut iter = vec![1, 2, 3, 4].iterate();
+    assert_eq!(iter.by_ref().find(|x| *x > 1), Some(2));
+    assert_eq!(drain(iter), vec![3, 4]);
+}
+
+#[test]
+fn positions() {
+    let iter = source("iterate".chars()).positions(|c| "aeiou".contains(c));
+    assert_eq!(drain(iter), vec![0, 2, 4, 6]);
+
+    let iter = source("rhythm".chars()).positions(|c| "aeiou".contains(c));
+    assert_eq!(drain(iter), vec![]);
+}
+
+#[test]
+fn find_map() {
+    let found = source(vec!["a", "12", "b", "34"]).find_map(|s| s.parse::<i32>().ok());
+    assert_eq!(found, Some(12));
+
+    let found = source(vec!["a", "b"]).find_map(|s| s.parse::<i32>().ok());
+    assert_eq!(found, None);
+}
+
+#[test]
+fn unzip() {
+    let (nums, chars): (Bag<i32>, Bag<char>) = source([(1, 'a'), (2, 'b')]).unzip();
+    assert_eq!(nums, Bag(vec![1, 2]));
+    assert_eq!(chars, Bag(vec!['a', 'b']));
+
+    let (nums, chars): (Bag<i32>, Bag<char>) = source(Vec::new()).unzip();
+    assert_eq!(nums, Bag(vec![]));
+    assert_eq!(chars, Bag(vec![]));
+}
+
+#[test]
+fn unzip_into_std_collections() {
+    let (nums, chars): (Vec<_>, Vec<_>) = [(1, 'a'), (2, 'b')].unzip();
+    assert_eq!((nums, chars), (vec![1, 2], vec!['a', 'b']));
+
+    let (nums, word): (Vec<i32>, String) = [(1, 'o'), (2, 'k')].unzip();
+    assert_eq!((nums, word.as_str()), (vec![1, 2], "ok"));
+
+    let (nums, chars): (Vec<i32>, Vec<char>) = [(0, 'x'); 0].unzip();
+    assert!(nums.is_empty() && chars.is_empty());
+}
+
+#[test]
+fn tee() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let iter = source(vec![1, 2, 3]).map(move |x| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        x * 2
+    });
+
+    let (left, right) = iter.tee();
+    let left = thread::spawn(move || drain(left));
+    let right = thread::spawn(move || drain(right));
+    assert_eq!(left.join().unwrap(), vec![2, 4, 6]);
+    assert_eq!(right.join().unwrap(), vec![2, 4, 6]);
+    assert_eq!(calls.load(Ordering::SeqCst), 6);
+}
+
+#[test]
+fn partition() {
+    let (even, odd): (Bag<i32>, Bag<i32>) = source(1..=6).partition(|x| x % 2 == 0);
+    assert_eq!(even, Bag(vec![2, 4, 6]));
+    assert_eq!(odd, Bag(vec![1, 3, 5]));
+
+    let (yes, no): (Bag<i32>, Bag<i32>) = source(1..=3).partition(|_| true);
+    assert_eq!(yes, Bag(vec![1, 2, 3]));
+    assert_eq!(no, Bag(vec![]));
+
+    let (yes, no): (Bag<i32>, Bag<i32>) = source(0..0).partition(|_| true);
+    assert_eq!(yes, Bag(vec![]));
+    assert_eq!(no, Bag(vec![]));
+
+    let (even, odd): (Vec<_>, Vec<_>) = Iterate::partition(0..10, |x| x % 2 == 0);
+    assert_eq!(even, vec![0, 2, 4, 6, 8]);
+    assert_eq!(odd, vec![1, 3, 5, 7, 9]);
+}
+
+#[test]
+fn position() {
+    assert_eq!(source([1, 2, 3, 4]).position(|x| *x == 3), Some(2));
+    assert_eq!(source([1, 2, 3, 4]).position(|x| *x == 1), Some(0));
+    assert_eq!(source([1, 2, 3, 4]).position(|x| *x == 5), None);
+
+    let mut seen = vec![];
+    let found = source([1, 2, 3, 2]).position(|x| {
+        seen.push(*x);
+        *x == 2
+    });
+    assert_eq!(found, Some(1));
+    assert_eq!(seen, vec![1, 2]);
+}
+
+#[test]
+fn rposition() {
+    let items = [1, 2, 3, 2, 1];
+    assert_eq!(source(&items[..]).rposition(|x| **x == 2), Some(3));
+    assert_eq!(source(&items[..]).rposition(|x| **x == 1), Some(4));
+    assert_eq!(source(&items[..]).rposition(|x| **x == 3), Some(2));
+    assert_eq!(source(&items[..]).rposition(|x| **x == 5), None);
+}
+
+#[test]
+fn sources() {
+    use iterate_trait::sources::{empty, once, repeat};
+
+    assert_eq!(drain(once(1)), vec![1]);
+    assert_eq!(exact_len(once(1)), 1);
+    assert_eq!(drain(empty::<u8>()), vec![]);
+    assert_eq!(exact_len(empty::<u8>()), 0);
+
+    assert_fused(&repeat(1));
+    let mut iter = repeat('a').iterate();
+    assert_eq!(iter.next(), Some('a'));
+    assert_eq!(iter.next(), Some('a'));
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+    assert_eq!(repeat('a').nth(100), Some('a'));
+}
+
+#[test]
+fn from_fn() {
+    use iterate_trait::sources::from_fn;
+
+    let mut count = 0;
+    let iter = from_fn(|| {
+        count += 1;
+        if count <= 3 {
+            Some(count)
+        } else {
+            None
+        }
+    });
+    assert_eq!(drain(iter), vec![1, 2, 3]);
+}
+
+#[test]
+fn successors() {
+    use iterate_trait::sources::successors;
+
+    let iter = successors(Some(1u8), |x| x.checked_mul(2));
+    assert_fused(&iter);
+    assert_eq!(drain(iter), vec![1, 2, 4, 8, 16, 32, 64, 128]);
+    assert_eq!(drain(successors(None::<u8>, |x| Some(*x))), vec![]);
+}
+
+#[test]
+fn by_ref() {
+    let mut iter = source(1..=5).iterate();
+    let head = drain(iter.by_ref().take_while_inclusive(|x| *x < 3));
+    let rest = drain(iter.by_ref());
+    assert_eq!(head, vec![1, 2, 3]);
+    assert_eq!(rest, vec![4, 5]);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn all() {
+    assert!(source([2, 4, 6]).all(|x| x % 2 == 0));
+    assert!(source(Vec::<u8>::new()).all(|_| false));
+
+    let mut seen = vec![];
+    let res = source([2, 3, 4]).all(|x| {
+        seen.push(x);
+        x % 2 == 0
+    });
+    assert!(!res);
+    assert_eq!(seen, vec![2, 3]);
+}
+
+#[test]
+fn all_stops_pulling_at_first_false() {
+    let mut iter = vec![2, 4, 5, 6, 8].iterate();
+    assert!(!iter.by_ref().all(|x| x % 2 == 0));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(drain(iter), vec![6, 8]);
+}
+
+#[test]
+fn pad_using() {
+    let iter = source(Vec::<usize>::new()).pad_using(3, |i| i * 10);
+    assert_eq!(iter.iterate().size_hint(), (3, Some(3)));
+    let iter = source(Vec::<usize>::new()).pad_using(3, |i| i * 10);
+    assert_eq!(drain(iter), vec![0, 10, 20]);
+
+    let iter = source(vec![1, 2]).pad_using(4, |i| i * 10);
+    assert_eq!(drain(iter), vec![1, 2, 20, 30]);
+
+    let mut calls = 0;
+    let iter = source(vec![1, 2, 3, 4]).pad_using(2, |i| {
+        calls += 1;
+        i
+    });
+    assert_eq!(drain(iter), vec![1, 2, 3, 4]);
+    assert_eq!(calls, 0);
+
+    let iter = [1, 2].pad_using(5, |i| i as i32);
+    assert_eq!(drain(iter), vec![1, 2, 2, 3, 4]);
+
+    let mut iter = Flaky(0).pad_using(4, |i| 100 + i as u32).iterate();
+    assert_fused(&iter);
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.size_hint(), (3, None));
+    assert_eq!(iter.next(), Some(101));
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    assert_eq!(drain(&mut iter), vec![102, 103]);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn repeat_with() {
+    use iterate_trait::sources::repeat_with;
+
+    let mut iter = repeat_with(|| String::from("hi")).iterate();
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+    let (a, b, c) = (
+        iter.next().unwrap(),
+        iter.next().unwrap(),
+        iter.next().unwrap(),
+    );
+    assert_eq!((a.as_str(), b.as_str(), c.as_str()), ("hi", "hi", "hi"));
+    assert_ne!(a.as_ptr(), b.as_ptr());
+    assert_ne!(b.as_ptr(), c.as_ptr());
+}
+
+#[test]
+fn any() {
+    assert!(!source([1, 3, 5]).any(|x| x % 2 == 0));
+    assert!(!source(Vec::<u8>::new()).any(|_| true));
+
+    let mut seen = vec![];
+    let res = source([1, 2, 3]).any(|x| {
+        seen.push(x);
+        x % 2 == 0
+    });
+    assert!(res);
+    assert_eq!(seen, vec![1, 2]);
+}
+
+#[test]
+fn any_after_adapters() {
+    let mut checked = 0;
+    let found = ["1", "x", "20", "3", "40"]
+        .filter_map(|s| s.parse::<u32>().ok())
+        .map(|n| n * 2)
+        .any(|n| {
+            checked += 1;
+            n > 10
+        });
+    assert!(found);
+    assert_eq!(checked, 2);
+
+    assert!(!vec!["x", "y"]
+        .filter_map(|s| s.parse::<u32>().ok())
+        .any(|_| true));
+}
+
+/// An item which is ordered by its key but remembers where it came from.
+#[derive(Debug, Clone, Copy)]
+struct Tagged(u32, char);
+
+impl PartialEq for Tagged {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for Tagged {}
+
+impl PartialOrd for Tagged {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Tagged {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+#[test]
+fn max_min() {
+    assert_eq!(source([1, 3, 2]).max(), Some(3));
+    assert_eq!(source([2, 1, 3]).min(), Some(1));
+    assert_eq!(source(Vec::<u8>::new()).max(), None);
+    assert_eq!(source(Vec::<u8>::new()).min(), None);
+}
+
+#[test]
+fn max_min_ties() {
+    let items = [
+        Tagged(1, 'a'),
+        Tagged(3, 'b'),
+        Tagged(1, 'c'),
+        Tagged(3, 'd'),
+    ];
+    assert_eq!(source(items).max().unwrap().1, 'd');
+    assert_eq!(source(items).min().unwrap().1, 'a');
+    assert_eq!(items.iter().max().unwrap().1, 'd');
+    assert_eq!(items.iter().min().unwrap().1, 'a');
+}
+
+#[test]
+fn max_min_pipeline() {
+    let items = [
+        Tagged(2, 'a'),
+        Tagged(0, 'b'),
+        Tagged(2, 'c'),
+        Tagged(0, 'd'),
+    ];
+    let tagged = |t: Tagged| Tagged(t.0 + 1, t.1);
+    assert_eq!(source(items).map(tagged).max().map(|t| t.1), Some('c'));
+    assert_eq!(source(items).map(tagged).min().map(|t| t.1), Some('b'));
+    assert_eq!(Iterate::max([7]), Some(7));
+    assert_eq!(Iterate::min([(); 0]), None);
+}
+
+#[test]
+fn skip_last() {
+    for len in 0..6 {
+        for n in 0..8 {
+            let mut expected: Vec<usize> = drain(0..len);
+            expected.truncate(len.saturating_sub(n));
+            assert_eq!(drain(source(0..len).skip_last(n)), expected);
+
+            let mut iter = source(0..len).skip_last(n).iterate();
+            assert_eq!(iter.size_hint(), (expected.len(), Some(expected.len())));
+            iter.next();
+            let left = expected.len().saturating_sub(1);
+            assert_eq!(iter.size_hint(), (left, Some(left)));
+        }
+    }
+}
+
+#[test]
+fn windows() {
+    let iter = source([1, 2, 3, 4]).windows(2);
+    assert_eq!(iter.iterate().size_hint(), (3, Some(3)));
+    let iter = source([1, 2, 3, 4]).windows(2);
+    assert_eq!(drain(iter), vec![vec![1, 2], vec![2, 3], vec![3, 4]]);
+    assert_eq!(drain(source([1, 2, 3]).windows(3)), vec![vec![1, 2, 3]]);
+    assert_eq!(drain(source([1, 2]).windows(3)), Vec::<Vec<i32>>::new());
+}
+
+#[test]
+#[should_panic]
+fn windows_of_zero() {
+    source([1, 2]).windows(0);
+}
+
+#[test]
+fn max_by_min_by() {
+    let floats = [2.5, -1.0, 7.25, 0.0];
+    let max = source(floats).max_by(|a, b| a.partial_cmp(b).unwrap());
+    let min = source(floats).min_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(max, Some(7.25));
+    assert_eq!(min, Some(-1.0));
+
+    let items = [(1.0, 'a'), (3.0, 'b'), (1.0, 'c'), (3.0, 'd')];
+    let cmp = |a: &(f64, char), b: &(f64, char)| a.0.partial_cmp(&b.0).unwrap();
+    assert_eq!(source(items).max_by(cmp), items.iter().copied().max_by(cmp));
+    assert_eq!(source(items).min_by(cmp), items.iter().copied().min_by(cmp));
+    assert_eq!(source(items).max_by(cmp).unwrap().1, 'd');
+    assert_eq!(source(items).min_by(cmp).unwrap().1, 'a');
+}
+
+#[test]
+fn max_by_min_by_total_cmp() {
+    let floats = [2.5, -0.0, 7.25, 0.0, -1.0];
+    assert_eq!(floats.max_by(f64::total_cmp), Some(7.25));
+    assert_eq!(floats.min_by(f64::total_cmp), Some(-1.0));
+
+    let floats = [1.0, f64::NAN, -f64::NAN, f64::INFINITY];
+    let max = floats.max_by(f64::total_cmp).unwrap();
+    let min = floats.min_by(f64::total_cmp).unwrap();
+    assert!(max.is_nan() && max.is_sign_positive());
+    assert!(min.is_nan() && min.is_sign_negative());
+
+    let zeros = [0.0, -0.0].min_by(f64::total_cmp).unwrap();
+    assert!(zeros.is_sign_negative());
+}
+
+#[test]
+fn windows_overlap_and_count() {
+    for len in 0..8usize {
+        for n in 1..5 {
+            let windows = drain(source(0..len).windows(n));
+            assert_eq!(windows.len(), (len + 1).saturating_sub(n));
+            for (i, window) in windows.iter().enumerate() {
+                assert_eq!(*window, drain(i..i + n));
+            }
+        }
+    }
+}
+
+#[test]
+fn chunks() {
+    let iter = source(1..=7).chunks(3);
+    assert_eq!(iter.iterate().size_hint(), (3, Some(3)));
+    let iter = source(1..=7).chunks(3);
+    assert_eq!(drain(iter), vec![vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+    let iter = source(1..=3).chunks(1);
+    assert_eq!(drain(iter), vec![vec![1], vec![2], vec![3]]);
+    assert_eq!(drain(source(0..0).chunks(2)), Vec::<Vec<i32>>::new());
+}
+
+#[test]
+fn array_chunks() {
+    let iter = source([1u8, 2, 3, 4, 5]).array_chunks::<2>();
+    assert_eq!(iter.iterate().size_hint(), (2, Some(2)));
+    let iter = source([1u8, 2, 3, 4, 5]).array_chunks::<2>();
+    assert_eq!(drain(iter), vec![[1, 2], [3, 4]]);
+    let iter = source([1u8, 2, 3]).array_chunks::<1>();
+    assert_eq!(drain(iter), vec![[1], [2], [3]]);
+    let iter = source([1u8, 2]).array_chunks::<3>();
+    assert_eq!(drain(iter), Vec::<[u8; 3]>::new());
+}
+
+#[test]
+fn chunks_size_hint() {
+    for len in 0..10usize {
+        for n in 1..4 {
+            let chunks = drain(source(0..len).chunks(n));
+            let expected = len.div_ceil(n);
+            assert_eq!(chunks.len(), expected);
+            assert!(chunks.iter().all(|chunk| !chunk.is_empty()));
+            let hint = source(0..len).chunks(n).iterate().size_hint();
+            assert_eq!(hint, (expected, Some(expected)));
+        }
+    }
+    assert_eq!(
+        drain(source(1..=6).chunks(3)),
+        vec![vec![1, 2, 3], vec![4, 5, 6]]
+    );
+}
+
+#[test]
+#[should_panic]
+fn chunks_of_zero() {
+    source([1, 2]).chunks(0);
+}
+
+#[test]
+fn max_by_key_min_by_key() {
+    let words = ["a", "ccc", "bb", "ddd", "e"];
+    assert_eq!(source(words).max_by_key(|s| s.len()), Some("ddd"));
+    assert_eq!(source(words).min_by_key(|s| s.len()), Some("a"));
+    assert_eq!(words.iter().max_by_key(|s| s.len()), Some(&"ddd"));
+    assert_eq!(words.iter().min_by_key(|s| s.len()), Some(&"a"));
+    assert_eq!(source(Vec::<&str>::new()).max_by_key(|s| s.len()), None);
+}
+
+#[test]
+fn max_by_key_min_by_key_evaluate_key_once() {
+    let words = ["ccc", "a", "dddd", "bb", "e"];
+
+    let mut calls = 0;
+    let longest = words.max_by_key(|s| {
+        calls += 1;
+        s.len()
+    });
+    assert_eq!((longest, calls), (Some("dddd"), words.len()));
+
+    let mut calls = 0;
+    let shortest = words.min_by_key(|s| {
+        calls += 1;
+        s.len()
+    });
+    assert_eq!((shortest, calls), (Some("a"), words.len()));
+}
+
+#[test]
+fn split() {
+    fn split(bytes: &[u8]) -> Vec<Vec<u8>> {
+        drain(source(bytes.to_vec()).split(|b| *b == b'\n'))
+    }
+    fn std_split(bytes: &[u8]) -> Vec<Vec<u8>> {
+        bytes.split(|b| *b == b'\n').map(|s| s.to_vec()).collect()
+    }
+
+    assert_eq!(split(b"ab\ncd"), vec![b"ab".to_vec(), b"cd".to_vec()]);
+    assert_eq!(split(b"\nab"), vec![vec![], b"ab".to_vec()]);
+    assert_eq!(split(b"ab\n"), vec![b"ab".to_vec(), vec![]]);
+    assert_eq!(split(b"a\n\nb"), vec![b"a".to_vec(), vec![], b"b".to_vec()]);
+    for input in [&b""[..], b"\n", b"\n\n", b"a", b"\na\n", b"a\n\n\nb\n"] {
+        assert_eq!(split(input), std_split(input));
+    }
+}
+
+#[test]
+fn sum() {
+    assert_eq!(source([1, 2, 3]).sum::<i32>(), 6);
+    assert_eq!(source([0.5, 1.5, 2.0]).sum::<f64>(), 4.0);
+    assert_eq!(source(0..0).sum::<i32>(), 0);
+    assert_eq!(source(Vec::<f32>::new()).sum::<f32>(), 0.0);
+}
+
+#[test]
+fn sum_fallible() {
+    let sum: Result<i32, &str> = source([Ok(1), Ok(2), Ok(3)]).sum();
+    assert_eq!(sum, Ok(6));
+
+    let mut pulled = 0;
+    let sum: Result<i32, &str> = source([Ok(1), Err("first"), Err("second"), Ok(4)])
+        .map(|x| {
+            pulled += 1;
+            x
+        })
+        .sum();
+    assert_eq!(sum, Err("first"));
+    assert_eq!(pulled, 2);
+
+    assert_eq!(
+        source([Some(1.5), Some(2.5)]).sum::<Option<f64>>(),
+        Some(4.0)
+    );
+    assert_eq!(source([Some(1), None, Some(3)]).sum::<Option<u8>>(), None);
+}
+
+#[test]
+fn dedup() {
+    assert_eq!(
+        drain(source([1, 1, 2, 3, 3, 3, 2]).dedup()),
+        vec![1, 2, 3, 2]
+    );
+    assert_eq!(drain(source(Vec::<u8>::new()).dedup()), vec![]);
+    assert_eq!(drain(source([7]).dedup()), vec![7]);
+}
+
+#[test]
+fn dedup_by() {
+    let iter = source([1, 2, 4, 5, 7, 10]).dedup_by(|a, b| b - a <= 1);
+    assert_eq!(drain(iter), vec![1, 4, 7, 10]);
+
+    let iter = source(["a", "A", "b", "B", "a"]).dedup_by_key(|s| s.to_lowercase());
+    assert_eq!(drain(iter), vec!["a", "b", "a"]);
+}
+
+#[test]
+fn map_ok_filter_ok() {
+    let items: Vec<Result<i32, &str>> = vec![Ok(1), Err("a"), Ok(2), Ok(3), Err("b"), Ok(4)];
+    let iter = source(items).map_ok(|x| x * 10).filter_ok(|x| *x != 30);
+    assert_eq!(
+        drain(iter),
+        vec![Ok(10), Err("a"), Ok(20), Err("b"), Ok(40)]
+    );
+
+    let items: Vec<Result<i32, &str>> = vec![Ok(1), Ok(2)];
+    let iter = source(items).map_ok(|x| x + 1).filter_ok(|x| x % 2 == 0);
+    assert_eq!(drain(iter), vec![Ok(2)]);
+
+    let items: Vec<Result<i32, &str>> = vec![Ok(1), Err("a"), Ok(2), Err("b")];
+    let r: Result<Vec<i32>, &str> = source(items)
+        .map_ok(|x| x * 10)
+        .filter_ok(|x| *x != 10)
+        .collect();
+    assert_eq!(r, Err("a"));
+
+    let items: Vec<Result<i32, &str>> = vec![Ok(1), Ok(2), Ok(3), Ok(4)];
+    let r: Result<Vec<i32>, &str> = source(items)
+        .map_ok(|x| x * 10)
+        .filter_ok(|x| *x != 30)
+        .collect();
+    assert_eq!(r, Ok(vec![10, 20, 40]));
+}
+
+#[test]
+fn product() {
+    assert_eq!(source(1..=5).product::<u64>(), 120);
+    assert_eq!(source([0.5, 4.0]).product::<f64>(), 2.0);
+    assert_eq!(source(0..0).product::<i32>(), 1);
+    assert_eq!(source(Vec::<f32>::new()).product::<f32>(), 1.0);
+}
+
+#[test]
+fn product_fallible() {
+    let factorial: Option<u64> = source(1..=20u64).map(Some).product();
+    assert_eq!(factorial, Some(2_432_902_008_176_640_000));
+    assert_eq!(source([0.5, 0.5, 8.0]).product::<f64>(), 2.0);
+
+    let checked = |x: u8| if x < 16 { Ok(x) } else { Err(x) };
+    let product: Result<u8, u8> = source([2, 3, 4]).map(checked).product();
+    assert_eq!(product, Ok(24));
+
+    let mut pulled = 0;
+    let product: Result<u8, u8> = source([2, 30, 40, 4])
+        .map(|x| {
+            pulled += 1;
+            checked(x)
+        })
+        .product();
+    assert_eq!(product, Err(30));
+    assert_eq!(pulled, 2);
+
+    assert_eq!(source([Some(2), None]).product::<Option<i32>>(), None);
+}
+
+#[test]
+fn flatten_ok() {
+    let items = vec![
+        Ok(source(vec![1, 2])),
+        Err("bad"),
+        Ok(source(vec![])),
+        Ok(source(vec![3])),
+    ];
+    let iter = source(items).flatten_ok();
+    assert_eq!(drain(iter), vec![Ok(1), Ok(2), Err("bad"), Ok(3)]);
+}
+
+#[test]
+fn map_err() {
+    #[derive(Debug, PartialEq)]
+    enum MyError {
+        Parse(std::num::ParseIntError),
+    }
+
+    let r: Result<Vec<i32>, MyError> = source(vec!["1", "x", "3"])
+        .map(str::parse)
+        .map_err(MyError::Parse)
+        .collect();
+    assert!(matches!(r, Err(MyError::Parse(_))));
+
+    let r: Result<Vec<i32>, MyError> = source(vec!["1", "3"])
+        .map(str::parse)
+        .map_err(MyError::Parse)
+        .collect();
+    assert_eq!(r, Ok(vec![1, 3]));
+}
+
+#[test]
+fn try_fold() {
+    let sum = source([1, 2, 3]).try_fold(0, |acc, x| Ok::<_, ()>(acc + x));
+    assert_eq!(sum, Ok(6));
+
+    let mut seen = vec![];
+    let sum = source([1, 2, -1, 3]).try_fold(0, |acc, x| {
+        seen.push(x);
+        if x < 0 {
+            Err(format!("negative value after summing to {}", acc))
+        } else {
+            Ok(acc + x)
+        }
+    });
+    assert_eq!(sum, Err("negative value after summing to 3".to_owned()));
+    assert_eq!(seen, vec![1, 2, -1]);
+}
+
+#[test]
+fn try_fold_stops_pulling_after_error() {
+    use std::cell::Cell;
+
+    #[derive(Debug, PartialEq)]
+    struct Overflow(u8);
+
+    let pulled = Cell::new(0);
+    let res = source([100u8, 100, 100, 100])
+        .map(|x| {
+            pulled.set(pulled.get() + 1);
+            x
+        })
+        .try_fold(0u8, |acc, x| acc.checked_add(x).ok_or(Overflow(acc)));
+    assert_eq!(res, Err(Overflow(200)));
+    assert_eq!(pulled.get(), 3);
+}
+
+#[test]
+fn try_for_each_write() {
+    use std::io::{self, Write};
+
+    /// A writer which fails once it has been written to `limit` times.
+    struct Limited {
+        buf: Vec<u8>,
+        limit: usize,
+    }
+
+    impl Write for Limited {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.limit == 0 {
+                return Err(io::Error::other("full"));
+            }
+            self.limit -= 1;
+            self.buf.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut out = Limited {
+        buf: vec![],
+        limit: 2,
+    };
+    let res = source([b"a", b"b", b"c"]).try_for_each(|item| out.write_all(item));
+    assert_eq!(res.unwrap_err().kind(), io::ErrorKind::Other);
+    assert_eq!(out.buf, b"ab");
+}
+
+#[test]
+fn collect_vec() {
+    let vec: Vec<_> = source(0..5).collect();
+    assert_eq!(vec, vec![0, 1, 2, 3, 4]);
+    let vec: Vec<u8> = source(0..0).collect();
+    assert_eq!(vec, vec![]);
+
+    let vec: Vec<_> = source(0..100).map(|x| x * 2).collect();
+    assert_eq!(vec.len(), 100);
+    assert_eq!(vec.capacity(), 100);
+}
+
+#[test]
+fn flatten_ok_interleaving() {
+    let items = vec![
+        Err(1),
+        Ok(source(vec!['a'])),
+        Err(2),
+        Err(3),
+        Ok(source(vec!['b', 'c'])),
+    ];
+    let items: Vec<_> = source(items).flatten_ok().collect();
+    assert_eq!(
+        items,
+        vec![Err(1), Ok('a'), Err(2), Err(3), Ok('b'), Ok('c')]
+    );
+
+    let mut iter = source(vec![Ok::<_, ()>(source(vec![1, 2]))])
+        .flatten_ok()
+        .iterate();
+    assert_eq!(iter.next(), Some(Ok(1)));
+    assert_eq!(iter.size_hint(), (1, Some(1)));
+    assert_eq!(iter.next(), Some(Ok(2)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn collect_into() {
+    let mut vec = vec![1, 2];
+    source([3, 4]).collect_into(&mut vec).push(5);
+    assert_eq!(vec, vec![1, 2, 3, 4, 5]);
+
+    let mut s = String::from("ab");
+    source(['c', 'd']).collect_into(&mut s);
+    source(["ef", "g"]).collect_into(&mut s);
+    assert_eq!(s, "abcdefg");
+
+    let mut map = std::collections::HashMap::new();
+    map.insert("a", 1);
+    map.insert("b", 2);
+    source([("b", 20), ("c", 30)]).collect_into(&mut map);
+    assert_eq!(map.len(), 3);
+    assert_eq!((map["a"], map["b"], map["c"]), (1, 20, 30));
+
+    let mut set: std::collections::HashSet<_> = [1, 2].iter().copied().collect();
+    source([2, 3]).collect_into(&mut set);
+    assert_eq!(set.len(), 3);
+}
+
+#[test]
+fn extend_collect() {
+    let mut vec = vec![0, 1];
+    vec.extend_collect(source([1, 2, 3]).map(|x| x * 10).dedup());
+    assert_eq!(vec, vec![0, 1, 10, 20, 30]);
+}
+
+#[test]
+fn collect_string() {
+    let s: String = source(['a', 'b', 'c']).collect();
+    assert_eq!(s, "abc");
+    let s: String = source(["foo", "bar"]).collect();
+    assert_eq!(s, "foobar");
+    let s: String = source(Vec::<char>::new()).collect();
+    assert_eq!(s, "");
+}
+
+#[test]
+fn while_some() {
+    let pulled = std::cell::Cell::new(0);
+    let items = vec![Some(1), Some(2), None, Some(3), Some(4)];
+    let iter = source(items.into_iter().inspect(|_| pulled.set(pulled.get() + 1)));
+    let mut iter = iter.while_some().iterate();
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(pulled.get(), 3);
+
+    let items: Vec<_> = source(vec![Some('a'), Some('b')]).while_some().collect();
+    assert_eq!(items, vec!['a', 'b']);
+}
+
+#[test]
+fn group_by() {
+    let groups: Vec<_> = source([(1, 'a'), (1, 'b'), (2, 'c')])
+        .group_by(|pair| pair.0)
+        .map(|(key, group)| {
+            (
+                key,
+                group.into_iter().map(|pair| pair.1).collect::<Vec<_>>(),
+            )
+        })
+        .collect();
+    assert_eq!(groups, vec![(1, vec!['a', 'b']), (2, vec!['c'])]);
+
+    let groups: Vec<_> = source([1, 1, 2, 1]).group_by(|x| *x).collect();
+    assert_eq!(groups, vec![(1, vec![1, 1]), (2, vec![2]), (1, vec![1])]);
+
+    let groups: Vec<(u8, Vec<u8>)> = source(Vec::<u8>::new()).group_by(|x| *x).collect();
+    assert_eq!(groups, vec![]);
+}
+
+#[test]
+fn collect_hash_map() {
+    use std::collections::HashMap;
+
+    let map: HashMap<_, _> = source([("a", 1), ("b", 2), ("a", 3)]).collect();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map["a"], 3);
+    assert_eq!(map["b"], 2);
+}
+
+#[test]
+fn update() {
+    #[derive(Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    fn assert_send<T: Send>(_: &T) {}
+
+    let points = vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }];
+    let iter = source(points).update(|p| p.y = -p.y).update(|p| p.x *= 10);
+    assert_send(&iter);
+    let points: Vec<_> = iter.collect();
+    assert_eq!(points, vec![Point { x: 10, y: -2 }, Point { x: 30, y: -4 }]);
+}
+
+#[test]
+fn interleave() {
+    let items: Vec<_> = source([1, 3, 5]).interleave(source([2, 4, 6])).collect();
+    assert_eq!(items, vec![1, 2, 3, 4, 5, 6]);
+    let items: Vec<_> = source([1, 2]).interleave(source([3, 4, 5, 6])).collect();
+    assert_eq!(items, vec![1, 3, 2, 4, 5, 6]);
+    let items: Vec<_> = source([1, 2, 3, 4]).interleave(source([5])).collect();
+    assert_eq!(items, vec![1, 5, 2, 3, 4]);
+}
+
+#[test]
+fn collect_sets() {
+    use std::collections::{BTreeSet, HashSet};
+
+    let set: HashSet<_> = source([1, 2, 2, 3]).collect();
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(&1) && set.contains(&2) && set.contains(&3));
+
+    let set: BTreeSet<_> = source([3, 1, 2, 2]).collect();
+    assert_eq!(set.len(), 3);
+    assert_eq!(set.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+}
+
+#[test]
+fn fold() {
+    assert_eq!(source([1, 2, 3]).fold(0, |acc, x| acc + x), 6);
+    let s = source(['a', 'b', 'c']).fold(String::new(), |mut s, c| {
+        s.push(c);
+        s.push(c);
+        s
+    });
+    assert_eq!(s, "aabbcc");
+    assert_eq!(source(Vec::<u8>::new()).fold(7, |_, _| 0), 7);
+}
+
+#[test]
+fn merge() {
+    let items: Vec<_> = source([1, 3, 5]).merge(source([2, 4, 6])).collect();
+    assert_eq!(items, vec![1, 2, 3, 4, 5, 6]);
+    let items: Vec<_> = source([1, 3, 5]).merge(source(vec![])).collect();
+    assert_eq!(items, vec![1, 3, 5]);
+    let items: Vec<_> = source(vec![]).merge(source([2, 4])).collect();
+    assert_eq!(items, vec![2, 4]);
+
+    let items: Vec<_> = source([Tagged(1, 'a'), Tagged(2, 'a')])
+        .merge(source([Tagged(1, 'b'), Tagged(2, 'b')]))
+        .map(|t| (t.0, t.1))
+        .collect();
+    assert_eq!(items, vec![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+}
+
+#[test]
+fn collect_btree_map() {
+    use std::collections::BTreeMap;
+
+    let map: BTreeMap<_, _> = source([("c", 3), ("a", 1), ("b", 2)]).collect();
+    let keys: Vec<_> = map.keys().copied().collect();
+    assert_eq!(keys, vec!["a", "b", "c"]);
+
+    let map: BTreeMap<_, _> = source([(2, 'x'), (1, 'y'), (2, 'z')]).collect();
+    let pairs: Vec<_> = map.into_iter().collect();
+    assert_eq!(pairs, vec![(1, 'y'), (2, 'z')]);
+}
+
+#[test]
+fn cartesian_product() {
+    let pairs: Vec<_> = source([1, 2])
+        .cartesian_product(source(['a', 'b']))
+        .collect();
+    assert_eq!(pairs, vec![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+
+    let pairs: Vec<(i32, char)> = source([1, 2]).cartesian_product(source(vec![])).collect();
+    assert!(pairs.is_empty());
+    let pairs: Vec<(i32, char)> = source(vec![]).cartesian_product(source(['a'])).collect();
+    assert!(pairs.is_empty());
+
+    let mut iter = Some(1).cartesian_product(Flaky(0)).iterate();
+    assert_fused(&iter);
+    assert_eq!(iter.next(), Some((1, 1)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn collect_vec_deque() {
+    use std::collections::VecDeque;
+
+    let mut deque: VecDeque<_> = source(0..4).collect();
+    assert_eq!(deque.pop_front(), Some(0));
+    assert_eq!(deque.pop_front(), Some(1));
+    assert_eq!(deque.pop_front(), Some(2));
+    assert_eq!(deque.pop_front(), Some(3));
+    assert_eq!(deque.pop_front(), None);
+}
+
+#[test]
+fn zip_longest() {
+    use iterate_trait::zip_longest::EitherOrBoth::{Both, Left, Right};
+
+    let pairs: Vec<_> = source([1, 2]).zip_longest(source(['a', 'b'])).collect();
+    assert_eq!(pairs, vec![Both(1, 'a'), Both(2, 'b')]);
+
+    let pairs: Vec<_> = source([1]).zip_longest(source(['a', 'b', 'c'])).collect();
+    assert_eq!(pairs, vec![Both(1, 'a'), Right('b'), Right('c')]);
+
+    let iter = source([1, 2, 3]).zip_longest(source(['a'])).iterate();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(drain(iter), vec![Both(1, 'a'), Left(2), Left(3)]);
+}
+
+#[test]
+fn collect_result() {
+    let res: Result<Vec<_>, &str> = source([Ok(1), Ok(2)]).collect();
+    assert_eq!(res, Ok(vec![1, 2]));
+
+    let mut pulled = 0;
+    let res: Result<Vec<_>, &str> = source([Ok(1), Err("x"), Ok(3)])
+        .map(|x| {
+            pulled += 1;
+            x
+        })
+        .collect();
+    assert_eq!(res, Err("x"));
+    assert_eq!(pulled, 2);
+}
+
+#[test]
+fn compare() {
+    assert!(source([1, 2, 3]).eq(source([1, 2, 3])));
+    assert!(!source([1, 2, 3]).eq(source([1, 2])));
+    assert!(source([1, 2]).ne(source([1, 3])));
+
+    assert!(source([1, 2]).lt(source([1, 3])));
+    assert!(!source([1, 2, 3]).lt(source([1, 2])));
+    assert!(source([1, 2]).lt(source([1, 2, 3])));
+    assert!(source([1, 2]).le(source([1, 2])));
+    assert!(source([2]).gt(source([1, 9])));
+    assert!(source([1, 2]).ge(source([1, 2])));
+
+    assert!(!source([f64::NAN]).lt(source([1.0])));
+    assert!(!source([f64::NAN]).ge(source([1.0])));
+}
+
+#[test]
+fn collect_option() {
+    let res: Option<Vec<_>> = source([Some(1), Some(2)]).collect();
+    assert_eq!(res, Some(vec![1, 2]));
+
+    let mut pulled = 0;
+    let res: Option<Vec<_>> = source([Some(1), None, Some(3)])
+        .map(|x| {
+            pulled += 1;
+            x
+        })
+        .collect();
+    assert_eq!(res, None);
+    assert_eq!(pulled, 2);
+}
+
+#[test]
+fn is_sorted() {
+    assert!(source([1, 2, 3]).is_sorted());
+    assert!(!source([1, 3, 2]).is_sorted());
+    assert!(source(Vec::<u8>::new()).is_sorted());
+    assert!(source([1]).is_sorted());
+    assert!(source([1, 1, 2]).is_sorted());
+    assert!(!source([1.0, f64::NAN]).is_sorted());
+
+    assert!(source([3, 2, 1]).is_sorted_by(|a, b| b.partial_cmp(a)));
+    assert!(source(["a", "bb", "cc"]).is_sorted_by_key(|s| s.len()));
+    assert!(!source(["bb", "a"]).is_sorted_by_key(|s: &&str| s.len()));
+
+    let mut pulled = 0;
+    let sorted = source([1, 3, 2, 4])
+        .map(|x| {
+            pulled += 1;
+            x
+        })
+        .is_sorted();
+    assert!(!sorted);
+    assert_eq!(pulled, 3);
+}
+
+#[test]
+fn collect_boxed_slice() {
+    let slice: Box<[_]> = source(0..3).collect();
+    assert_eq!(slice.len(), 3);
+    assert_eq!(&*slice, &[0, 1, 2]);
+}
+
+#[test]
+fn collect_unit() {
+    let mut seen = vec![];
+    source([1, 2, 3]).update(|x| seen.push(*x)).collect::<()>();
+    assert_eq!(seen, vec![1, 2, 3]);
+}
+
+#[test]
+fn option() {
+    assert_eq!(drain(None::<i32>), vec![]);
+    assert_eq!(drain(Some(42)), vec![42]);
+    assert_eq!(exact_len(Some(42)), 1);
+    assert_eq!(exact_len(None::<i32>), 0);
+    assert_fused(&Some(1));
+
+    let items: Vec<_> = Some(1).interleave(source([2, 3])).collect();
+    assert_eq!(items, vec![1, 2, 3]);
+    let items: Vec<_> = source([Some(1), None, Some(3)])
+        .map(|x| x.iterate().map(|x| x * 2).last())
+        .collect();
+    assert_eq!(items, vec![Some(2), None, Some(6)]);
+}
+
+#[test]
+fn result() {
+    assert_eq!(drain(Ok::<_, &str>(1)), vec![1]);
+    assert_eq!(drain(Err::<i32, _>("e")), vec![]);
+    assert_eq!(exact_len(Ok::<_, ()>(1)), 1);
+
+    let items: Vec<_> = source([Ok(1), Err("e"), Ok(3)]).ok().collect();
+    assert_eq!(items, vec![1, 3]);
+}
+
+#[test]
+fn try_collect() {
+    let res = source(["1", "2", "3"])
+        .map(|s| s.parse::<u8>())
+        .try_collect::<Vec<_>, _, _>();
+    assert_eq!(res, Ok(vec![1, 2, 3]));
+
+    let mut pulled = 0;
+    let res: Result<String, usize> = source(['a', 'b', '!', 'c'])
+        .map(|c| {
+            pulled += 1;
+            if c.is_alphabetic() {
+                Ok(c)
+            } else {
+                Err(pulled)
+            }
+        })
+        .try_collect();
+    assert_eq!(res, Err(3));
+    assert_eq!(pulled, 3);
+}
+
+#[test]
+fn array() {
+    let items: Vec<i32> = [1, 2, 3].collect();
+    assert_eq!(items, vec![1, 2, 3]);
+    assert_eq!(drain([(); 0]), vec![]);
+    assert_eq!(exact_len([1, 2, 3]), 3);
+    assert_eq!(
+        drain(["a".to_owned(), "b".to_owned()].rev()),
+        vec!["b", "a"]
+    );
+
+    let mut iter = [1, 2, 3].iterate();
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.len(), 2);
+}
+
+#[test]
+fn array_sum() {
+    assert_eq!([10, 20, 30].sum::<i32>(), 60);
+    assert_eq!(Iterate::map([10, 20, 30], |x| x / 10).sum::<i32>(), 6);
+    let empty: [i32; 0] = [];
+    assert_eq!(drain(empty), vec![]);
+    assert_eq!(exact_len(empty), 0);
+}
+
+#[test]
+fn range() {
+    assert_eq!(drain(0..5), vec![0, 1, 2, 3, 4]);
+    assert_eq!(drain(0..=2u64), vec![0, 1, 2]);
+    assert_eq!(drain(3..3usize), vec![]);
+    assert_eq!(drain('a'..='c'), vec!['a', 'b', 'c']);
+    assert_eq!(drain((0..3i64).iterate().rev()), vec![2, 1, 0]);
+    assert_eq!(exact_len(0..5u32), 5);
+    assert_eq!(exact_len(0..=5u8), 6);
+    assert_fused(&(0..1));
+}
+
+#[test]
+fn with_position() {
+    use iterate_trait::with_position::Position::{First, Last, Middle, Only};
+
+    assert_eq!(
+        drain([1, 2, 3].with_position()),
+        vec![First(1), Middle(2), Last(3)]
+    );
+    assert_eq!(drain([1].with_position()), vec![Only(1)]);
+    assert_eq!(drain([1, 2].with_position()), vec![First(1), Last(2)]);
+    assert_eq!(drain([0; 0].with_position()), vec![]);
+
+    let mut iter = [1, 2, 3].with_position().iterate();
+    assert_eq!(iter.len(), 3);
+    iter.next();
+    assert_eq!(iter.len(), 2);
+    iter.next();
+    iter.next();
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+
+    let mut iter = Flaky(1).with_position().iterate();
+    assert_fused(&iter);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+
+    let mut csv = String::new();
+    ["a", "b", "c"].with_position().for_each(|item| match item {
+        Last(s) | Only(s) => csv.push_str(s),
+        First(s) | Middle(s) => csv.push_str(&format!("{},", s)),
+    });
+    assert_eq!(csv, "a,b,c");
+}
+
+#[test]
+fn vec() {
+    let mut iter = vec![1, 2, 3].iterate();
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next_back(), Some(3));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(drain(iter), vec![2]);
+
+    let words = vec!["a".to_owned(), "b".to_owned()];
+    assert_eq!(words.map(|s| s + "!").collect::<Vec<_>>(), vec!["a!", "b!"]);
+}
+
+#[test]
+fn join() {
+    assert_eq!(["foo", "bar", "baz"].join(", "), "foo, bar, baz");
+    assert_eq!(source(Vec::<u8>::new()).join(", "), "");
+    assert_eq!(source([1]).join(", "), "1");
+    assert_eq!(vec![1, 2, 3].map(|x| x * 2).join("-"), "2-4-6");
+}
+
+#[test]
+fn format() {
+    use std::fmt::Write;
+
+    let mut buf = String::new();
+    write!(buf, "{}", [1, 2, 3].format(", ")).unwrap();
+    assert_eq!(buf, "1, 2, 3");
+
+    assert_eq!(format!("[{}]", source(Vec::<u8>::new()).format(", ")), "[]");
+    assert_eq!(format!("{:.1}", [1.0, 2.5].format("; ")), "1.0; 2.5");
+}
+
+#[test]
+#[should_panic(expected = "only be formatted once")]
+fn format_twice() {
+    let format = [1, 2].format(", ");
+    let _ = format!("{}", format);
+    let _ = format!("{}", format);
+}
+
+#[test]
+fn slice() {
+    let items = [1, 2, 3];
+    let slice = &items[..];
+    assert_eq!(slice.iterate().size_hint(), (3, Some(3)));
+    assert_eq!(slice.iterate().map(|x| *x).sum::<i32>(), 6);
+    assert_eq!(drain(slice.iterate().rev()), vec![&3, &2, &1]);
+    assert_eq!(exact_len(slice), 3);
+    assert_eq!(items, [1, 2, 3]);
+}
+
+#[test]
+fn counts() {
+    let counts = ['a', 'b', 'a', 'c', 'b', 'b'].counts();
+    assert_eq!(counts.len(), 3);
+    assert_eq!((counts[&'a'], counts[&'b'], counts[&'c']), (2, 3, 1));
+    assert!(source(Vec::<char>::new()).counts().is_empty());
+}
+
+#[test]
+fn slice_mut() {
+    let mut items = [1, 2, 3];
+    (&mut items[..]).for_each(|x| *x *= 2);
+    assert_eq!(items, [2, 4, 6]);
+
+    let slice = &mut items[..];
+    assert_eq!(exact_len(&mut *slice), 3);
+    if let Some(last) = slice.iterate().next_back() {
+        *last = 0;
+    }
+    assert_eq!(items, [2, 4, 0]);
+}
+
+#[test]
+fn vec_std_comparisons() {
+    let (a, b) = (vec![1, 2], vec![1, 3]);
+    assert!(PartialEq::eq(&a, &a.clone()));
+    assert!(PartialEq::ne(&a, &b));
+    assert!(PartialOrd::lt(&a, &b));
+    assert!(PartialOrd::ge(&b, &a));
+    assert_eq!(Ord::max(a.clone(), b.clone()), b);
+    assert_eq!(Ord::min(a.clone(), b), a);
+
+    assert!(Iterate::eq(a.clone(), a));
+}
+
+#[test]
+fn option_std_comparisons() {
+    assert_eq!(Ord::max(Some(1), Some(2)), Some(2));
+    assert_eq!(Ord::min(Some(1), None), None);
+    assert!(PartialEq::eq(&Some(1), &Some(1)));
+    assert!(PartialOrd::lt(&None, &Some(0)));
+}
+
+#[test]
+fn array_std_comparisons() {
+    assert_eq!(Ord::max([1, 2], [3, 4]), [3, 4]);
+    assert!(PartialEq::eq(&[1, 2], &[1, 2]));
+    assert!(PartialOrd::lt(&[1, 2], &[1, 3]));
+    assert!(Iterate::lt([1, 2], [1, 3]));
+}